@@ -0,0 +1,136 @@
+//! # Procedural macros for datetime-default
+//!
+//! Use them through the `macros` feature of `datetime-default`.
+//!
+use chrono::{DateTime, Offset, Timelike};
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, LitStr};
+
+/// # DateTime type with user specified default.
+///
+/// Parses an RFC 3339 literal at compile time,
+/// and expands to the `DateTimeDefaultAt` type whose default is that instant.
+/// A `Z` suffix selects `Utc`, and any other offset selects `FixedOffset`.
+/// The expansion names chrono through `datetime_default`,
+/// so callers don't need a `chrono` dependency of their own.
+///
+/// ```ignore
+/// #[derive(Default)]
+/// struct A {
+///     valid_from: datetime_default!("2000-01-01T00:00:00Z"),
+/// }
+/// ```
+#[proc_macro]
+pub fn datetime_default(input: TokenStream) -> TokenStream {
+    let literal = parse_macro_input!(input as LitStr);
+
+    expand(&literal)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(literal: &LitStr) -> syn::Result<TokenStream2> {
+    let value = literal.value();
+    let datetime = DateTime::parse_from_rfc3339(&value)
+        .map_err(|err| syn::Error::new(literal.span(), format!("invalid RFC 3339: {err}")))?;
+
+    if datetime.nanosecond() != 0 {
+        return Err(syn::Error::new(
+            literal.span(),
+            "fractional seconds are not supported",
+        ));
+    }
+
+    let epoch_secs = datetime.timestamp();
+    let offset_secs = datetime.offset().fix().local_minus_utc();
+
+    if value.ends_with(['Z', 'z']) {
+        return Ok(quote! {
+            ::datetime_default::DateTimeDefaultAt<::datetime_default::__chrono::Utc, #epoch_secs>
+        });
+    }
+
+    if offset_secs % 60 != 0 {
+        return Err(syn::Error::new(
+            literal.span(),
+            "offset must be a whole number of minutes",
+        ));
+    }
+    let offset_hours = offset_secs / 3600;
+    let offset_minutes = offset_secs % 3600 / 60;
+
+    Ok(quote! {
+        ::datetime_default::DateTimeDefaultAt<
+            ::datetime_default::__chrono::FixedOffset,
+            #epoch_secs,
+            #offset_hours,
+            #offset_minutes,
+        >
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::Span;
+    use syn::LitStr;
+
+    use super::expand;
+
+    fn expand_str(value: &str) -> Result<String, String> {
+        expand(&LitStr::new(value, Span::call_site()))
+            .map(|tokens| tokens.to_string())
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn utc() {
+        assert_eq!(
+            expand_str("2000-01-01T00:00:00Z").unwrap(),
+            ":: datetime_default :: DateTimeDefaultAt < :: datetime_default :: __chrono :: Utc , 946684800i64 >"
+        );
+    }
+
+    #[test]
+    fn fixed_offset() {
+        assert_eq!(
+            expand_str("2000-01-01T09:00:00+09:00").unwrap(),
+            ":: datetime_default :: DateTimeDefaultAt < :: datetime_default :: __chrono :: FixedOffset , 946684800i64 , 9i32 , 0i32 , >"
+        );
+    }
+
+    #[test]
+    fn negative_offset() {
+        assert_eq!(
+            expand_str("1999-12-31T19:00:00-05:00").unwrap(),
+            ":: datetime_default :: DateTimeDefaultAt < :: datetime_default :: __chrono :: FixedOffset , 946684800i64 , - 5i32 , 0i32 , >"
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(expand_str("2000-01-01").is_err());
+    }
+
+    #[test]
+    fn fractional_seconds() {
+        assert!(expand_str("2000-01-01T00:00:00.5Z").is_err());
+    }
+
+    #[test]
+    fn minute_offset() {
+        assert_eq!(
+            expand_str("2000-01-01T05:30:00+05:30").unwrap(),
+            ":: datetime_default :: DateTimeDefaultAt < :: datetime_default :: __chrono :: FixedOffset , 946684800i64 , 5i32 , 30i32 , >"
+        );
+    }
+
+    #[test]
+    fn negative_minute_offset() {
+        assert_eq!(
+            expand_str("1999-12-31T20:30:00-03:30").unwrap(),
+            ":: datetime_default :: DateTimeDefaultAt < :: datetime_default :: __chrono :: FixedOffset , 946684800i64 , - 3i32 , - 30i32 , >"
+        );
+    }
+}
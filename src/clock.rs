@@ -0,0 +1,210 @@
+//! # Clock of the current time used as default.
+//!
+//! `DateTimeDefaultNow::default()` reads the current time from this clock.
+//! The clock can be frozen per thread, so that structs with `#[derive(Default)]` are deterministic in tests.
+//! [`same_now`] freezes it at the current time, so that all fields of one struct share the same time.
+//!
+//! ```
+//! use chrono::{DateTime, Utc};
+//! use datetime_default::{clock, DateTimeDefaultNow};
+//!
+//! let frozen = DateTime::parse_from_rfc3339("2022-10-10T23:40:11Z")
+//!     .unwrap()
+//!     .with_timezone(&Utc);
+//!
+//! clock::with_frozen_now(frozen, || {
+//!     assert_eq!(DateTimeDefaultNow::<Utc>::default(), frozen);
+//! });
+//! ```
+//!
+use std::cell::Cell;
+
+use chrono::{DateTime, TimeZone, Utc};
+
+#[cfg(test)]
+const NOW: &str = "2022/10/10 23:40:11.695164300";
+
+thread_local! {
+    static FROZEN_NOW: Cell<Option<DateTime<Utc>>> = const { Cell::new(None) };
+}
+
+/// Returns the frozen time of the current thread, or the system time.
+pub fn now() -> DateTime<Utc> {
+    FROZEN_NOW.with(Cell::get).unwrap_or_else(system_now)
+}
+
+/// Freezes the current time of the current thread until [`reset_thread_local`] is called.
+pub fn set_thread_local<Tz: TimeZone>(now: DateTime<Tz>) {
+    FROZEN_NOW.with(|frozen| frozen.set(Some(now.with_timezone(&Utc))));
+}
+
+/// Unfreezes the current time of the current thread.
+pub fn reset_thread_local() {
+    FROZEN_NOW.with(|frozen| frozen.set(None));
+}
+
+/// Freezes the current time of the current thread while `f` runs.
+///
+/// The previous state is restored afterwards, even if `f` panics.
+pub fn with_frozen_now<Tz, F, T>(now: DateTime<Tz>, f: F) -> T
+where
+    Tz: TimeZone,
+    F: FnOnce() -> T,
+{
+    struct Restore(Option<DateTime<Utc>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            FROZEN_NOW.with(|frozen| frozen.set(self.0));
+        }
+    }
+
+    let _restore = Restore(FROZEN_NOW.with(|frozen| frozen.replace(Some(now.with_timezone(&Utc)))));
+
+    f()
+}
+
+/// Shares one current time among all `DateTimeDefaultNow` created while `f` runs.
+///
+/// ```
+/// use chrono::{FixedOffset, Utc};
+/// use datetime_default::{same_now, DateTimeDefaultNow};
+///
+/// #[derive(Default)]
+/// struct Record {
+///     created_at: DateTimeDefaultNow<Utc>,
+///     updated_at: DateTimeDefaultNow<FixedOffset, 9>,
+/// }
+///
+/// let record = same_now(Record::default);
+///
+/// assert_eq!(record.created_at.timestamp_nanos(), record.updated_at.timestamp_nanos());
+/// ```
+pub fn same_now<F, T>(f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    with_frozen_now(now(), f)
+}
+
+#[cfg(not(test))]
+fn system_now() -> DateTime<Utc> {
+    Utc::now()
+}
+
+#[cfg(test)]
+thread_local! {
+    // Lets a test of the current thread read the running system clock instead of `NOW`.
+    static RUNNING: Cell<bool> = const { Cell::new(false) };
+}
+
+#[cfg(test)]
+fn system_now() -> DateTime<Utc> {
+    if RUNNING.with(Cell::get) {
+        return Utc::now();
+    }
+
+    Utc.datetime_from_str(NOW, "%Y/%m/%d %H:%M:%S%.9f").unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, FixedOffset, Local, Utc};
+
+    use crate::{clock, DateTimeDefaultNow};
+
+    fn frozen() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2000-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn with_frozen_now() {
+        clock::with_frozen_now(frozen(), || {
+            assert_eq!(DateTimeDefaultNow::<Utc>::default(), frozen());
+            assert_eq!(
+                DateTimeDefaultNow::<Local>::default(),
+                frozen().with_timezone(&Local)
+            );
+            assert_eq!(
+                DateTimeDefaultNow::<FixedOffset, 9>::default().to_rfc3339(),
+                "2000-01-01T09:00:00+09:00"
+            );
+        });
+
+        assert_ne!(DateTimeDefaultNow::<Utc>::default(), frozen());
+    }
+
+    #[test]
+    fn nested_with_frozen_now() {
+        let inner = DateTime::parse_from_rfc3339("2010-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        clock::with_frozen_now(frozen(), || {
+            clock::with_frozen_now(inner, || {
+                assert_eq!(clock::now(), inner);
+            });
+            assert_eq!(clock::now(), frozen());
+        });
+    }
+
+    #[test]
+    fn restore_after_panic() {
+        let result = std::panic::catch_unwind(|| clock::with_frozen_now(frozen(), || panic!()));
+
+        assert!(result.is_err());
+        assert_ne!(clock::now(), frozen());
+    }
+
+    #[test]
+    fn same_now() {
+        #[derive(Default)]
+        struct Record {
+            created_at: DateTimeDefaultNow<Utc>,
+            updated_at: DateTimeDefaultNow<Local>,
+            published_at: DateTimeDefaultNow<FixedOffset, -5>,
+        }
+
+        let record = clock::with_frozen_now(frozen(), || clock::same_now(Record::default));
+
+        assert_eq!(record.created_at, frozen());
+        assert_eq!(record.updated_at, frozen().with_timezone(&Local));
+        assert_eq!(record.published_at.timestamp(), frozen().timestamp());
+    }
+
+    #[test]
+    fn same_now_running_clock() {
+        super::RUNNING.with(|running| running.set(true));
+        let tick = || std::thread::sleep(std::time::Duration::from_millis(1));
+
+        let before = DateTimeDefaultNow::<Utc>::default();
+        tick();
+        assert_ne!(DateTimeDefaultNow::<Utc>::default(), before);
+
+        let (created_at, updated_at) = clock::same_now(|| {
+            let created_at = DateTimeDefaultNow::<Utc>::default();
+            tick();
+            (created_at, DateTimeDefaultNow::<FixedOffset, 9>::default())
+        });
+
+        assert_eq!(created_at, updated_at);
+        assert!(created_at > before);
+
+        super::RUNNING.with(|running| running.set(false));
+    }
+
+    #[test]
+    fn set_thread_local() {
+        clock::set_thread_local(frozen());
+        assert_eq!(DateTimeDefaultNow::<Utc>::default(), frozen());
+
+        std::thread::spawn(|| assert_ne!(clock::now(), frozen()))
+            .join()
+            .unwrap();
+
+        clock::reset_thread_local();
+        assert_ne!(DateTimeDefaultNow::<Utc>::default(), frozen());
+    }
+}
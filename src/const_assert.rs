@@ -8,3 +8,70 @@ impl<const MIN: i32, const OFFSET_HOURS: i32, const MAX: i32>
         "FixedOffset::east out of bounds"
     );
 }
+
+pub struct AssertOffsetMinutes<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>;
+
+impl<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    AssertOffsetMinutes<OFFSET_HOURS, OFFSET_MINUTES>
+{
+    pub const OK: () = assert!(
+        -60 < OFFSET_MINUTES
+            && OFFSET_MINUTES < 60
+            && (OFFSET_HOURS == 0
+                || OFFSET_MINUTES == 0
+                || (OFFSET_HOURS > 0) == (OFFSET_MINUTES > 0)),
+        "FixedOffset::east minutes out of bounds"
+    );
+}
+
+pub struct AssertEpochSecs<const EPOCH_SECS: i64>;
+
+impl<const EPOCH_SECS: i64> AssertEpochSecs<EPOCH_SECS> {
+    // 262143-12-31T23:59:59Z and -262144-01-01T00:00:00Z, the limits of chrono.
+    const MAX: i64 = 8_210_298_412_799;
+    const MIN: i64 = -8_334_632_851_200;
+
+    pub const OK: () = assert!(
+        Self::MIN <= EPOCH_SECS && EPOCH_SECS <= Self::MAX,
+        "Utc::timestamp out of bounds"
+    );
+}
+
+pub struct AssertFromNowSecs<const OFFSET_SECS: i64>;
+
+impl<const OFFSET_SECS: i64> AssertFromNowSecs<OFFSET_SECS> {
+    // About 253,500 years, so the default stays in the range of chrono
+    // from any current time within 6,000 years of the UNIX epoch.
+    const MAX: i64 = 8_000_000_000_000;
+
+    pub const OK: () = assert!(
+        -Self::MAX <= OFFSET_SECS && OFFSET_SECS <= Self::MAX,
+        "Duration::seconds out of bounds"
+    );
+}
+
+pub struct AssertSubsecDigits<const SUBSEC_DIGITS: u16>;
+
+impl<const SUBSEC_DIGITS: u16> AssertSubsecDigits<SUBSEC_DIGITS> {
+    pub const OK: () = assert!(
+        SUBSEC_DIGITS <= 9,
+        "SubsecRound::trunc_subsecs digits out of bounds"
+    );
+}
+
+pub struct AssertFirstWeekday<const FIRST_WEEKDAY: u8>;
+
+impl<const FIRST_WEEKDAY: u8> AssertFirstWeekday<FIRST_WEEKDAY> {
+    pub const OK: () = assert!(FIRST_WEEKDAY < 7, "Weekday out of bounds");
+}
+
+pub struct AssertZeroOffset<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>;
+
+impl<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    AssertZeroOffset<OFFSET_HOURS, OFFSET_MINUTES>
+{
+    pub const OK: () = assert!(
+        OFFSET_HOURS == 0 && OFFSET_MINUTES == 0,
+        "OFFSET_HOURS and OFFSET_MINUTES are only supported for FixedOffset"
+    );
+}
@@ -2,6 +2,8 @@
 //!
 //! This document is written as a reservation for the future.
 //! `DateTimeDefault` will be implemented when `const &'static str` become available as generic parameters.
+//! Until then, use `DateTimeDefaultAt` with a UNIX timestamp,
+//! or the `datetime_default!` macro of the `macros` feature with an RFC 3339 literal.
 //!
 //! ```compile_fail
 //! use chrono::{Utc, TimeZone};
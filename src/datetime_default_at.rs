@@ -0,0 +1,119 @@
+use crate::{At, DateTimeWithDefault};
+
+/// # DateTime with user specified UNIX timestamp as default.
+///
+/// `EPOCH_SECS` is the number of non-leap seconds since 1970-01-01T00:00:00Z.
+///
+/// ```
+/// use chrono::{Utc, TimeZone};
+/// use datetime_default::DateTimeDefaultAt;
+///
+/// assert_eq!(
+///     DateTimeDefaultAt::<Utc, 946684800>::default(),
+///     Utc.datetime_from_str("2000/1/1 00:00:00", "%Y/%m/%d %H:%M:%S").unwrap()
+/// );
+/// ```
+pub type DateTimeDefaultAt<
+    Tz,
+    const EPOCH_SECS: i64,
+    const OFFSET_HOURS: i32 = 0,
+    const OFFSET_MINUTES: i32 = 0,
+> = DateTimeWithDefault<Tz, At<EPOCH_SECS>, OFFSET_HOURS, OFFSET_MINUTES>;
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::{DateTime, FixedOffset, Local, TimeZone, Utc};
+
+    use crate::DateTimeDefaultAt;
+
+    const Y2K: i64 = 946684800;
+
+    #[derive(Default)]
+    struct Test {
+        valid_from: DateTimeDefaultAt<Utc, Y2K>,
+        valid_to: DateTimeDefaultAt<Utc, 253402300799>,
+    }
+
+    fn print_datetime(datetime: &DateTime<Utc>) {
+        println!("{datetime}")
+    }
+
+    #[test]
+    fn default_derive_struct() {
+        Test::default();
+    }
+
+    #[test]
+    fn use_default_derive_struct() {
+        let test = Test::default();
+
+        assert_eq!(test.valid_from.to_rfc3339(), "2000-01-01T00:00:00+00:00");
+        assert_eq!(test.valid_to.to_rfc3339(), "9999-12-31T23:59:59+00:00");
+    }
+
+    #[test]
+    fn use_default_datetime() {
+        let datetime = DateTimeDefaultAt::<Utc, Y2K>::default();
+
+        print_datetime(&datetime)
+    }
+
+    #[test]
+    fn negative_epoch_secs() {
+        let datetime = DateTimeDefaultAt::<Utc, -86400>::default();
+
+        assert_eq!(datetime.to_rfc3339(), "1969-12-31T00:00:00+00:00");
+    }
+
+    #[test]
+    fn raise_max_offset_hours() {
+        DateTimeDefaultAt::<FixedOffset, Y2K, 23>::default();
+    }
+
+    #[test]
+    fn raise_min_offset_hours() {
+        DateTimeDefaultAt::<FixedOffset, Y2K, -23>::default();
+    }
+
+    #[test]
+    fn fixed_offset() {
+        let datetime = DateTimeDefaultAt::<FixedOffset, Y2K, 9>::default();
+
+        assert_eq!(datetime.to_rfc3339(), "2000-01-01T09:00:00+09:00");
+    }
+
+    #[test]
+    fn local() {
+        assert_eq!(
+            DateTimeDefaultAt::<Local, Y2K>::default(),
+            Utc.timestamp(Y2K, 0).with_timezone(&Local)
+        );
+    }
+
+    #[test]
+    fn convert_from() {
+        let now = Local::now();
+        let datetime = DateTimeDefaultAt::<Local, Y2K>::from(now);
+
+        assert_eq!(datetime, now)
+    }
+
+    #[test]
+    fn convert_into() {
+        let now = Local::now();
+        let datetime: DateTimeDefaultAt<Local, Y2K> = now.into();
+
+        assert_eq!(datetime, now)
+    }
+
+    #[test]
+    fn comparing1() {
+        assert!(DateTimeDefaultAt::<Local, Y2K>::default() <= Local::now())
+    }
+
+    #[test]
+    fn comparing2() {
+        assert!(DateTime::<Local>::default() <= DateTimeDefaultAt::<Local, Y2K>::default())
+    }
+}
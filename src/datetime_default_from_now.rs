@@ -0,0 +1,107 @@
+use crate::{DateTimeWithDefault, FromNow};
+
+/// # DateTime with current time plus `OFFSET_SECS` seconds as default.
+///
+/// Negative `OFFSET_SECS` are in the past.
+/// The current time is taken from [`clock::now`](crate::clock::now), like `DateTimeDefaultNow`.
+///
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use datetime_default::{clock, DateTimeDefaultFromNow};
+///
+/// const ONE_DAY: i64 = 24 * 60 * 60;
+///
+/// let now = DateTime::parse_from_rfc3339("2022-10-10T23:40:11Z").unwrap();
+///
+/// clock::with_frozen_now(now, || {
+///     assert_eq!(
+///         DateTimeDefaultFromNow::<Utc, ONE_DAY>::default().to_rfc3339(),
+///         "2022-10-11T23:40:11+00:00"
+///     );
+/// });
+/// ```
+///
+/// `OFFSET_SECS` is limited to ±8,000,000,000,000 seconds at compile time,
+/// so the default stays in the range of chrono.
+///
+/// ```compile_fail
+/// use chrono::Utc;
+/// use datetime_default::DateTimeDefaultFromNow;
+///
+/// let _ = DateTimeDefaultFromNow::<Utc, 8_300_000_000_000>::default();
+/// ```
+pub type DateTimeDefaultFromNow<
+    Tz,
+    const OFFSET_SECS: i64,
+    const OFFSET_HOURS: i32 = 0,
+    const OFFSET_MINUTES: i32 = 0,
+> = DateTimeWithDefault<Tz, FromNow<OFFSET_SECS>, OFFSET_HOURS, OFFSET_MINUTES>;
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::{DateTime, Duration, FixedOffset, Local, Utc};
+
+    use crate::{clock, DateTimeDefaultFromNow};
+
+    const ONE_DAY: i64 = 24 * 60 * 60;
+
+    #[derive(Default)]
+    struct Session {
+        expires_at: DateTimeDefaultFromNow<Utc, ONE_DAY>,
+        issued_before: DateTimeDefaultFromNow<Utc, -60>,
+    }
+
+    #[test]
+    fn use_default_derive_struct() {
+        let session = Session::default();
+
+        assert_eq!(
+            session.expires_at.to_rfc3339(),
+            "2022-10-11T23:40:11.695164300+00:00"
+        );
+        assert_eq!(
+            session.issued_before.to_rfc3339(),
+            "2022-10-10T23:39:11.695164300+00:00"
+        );
+    }
+
+    #[test]
+    fn follows_clock() {
+        let now = DateTime::parse_from_rfc3339("2000-01-01T00:00:00Z").unwrap();
+
+        clock::with_frozen_now(now, || {
+            assert_eq!(
+                DateTimeDefaultFromNow::<Utc, 3600>::default().to_rfc3339(),
+                "2000-01-01T01:00:00+00:00"
+            );
+        });
+    }
+
+    #[test]
+    fn fixed_offset() {
+        let datetime = DateTimeDefaultFromNow::<FixedOffset, ONE_DAY, 9>::default();
+
+        assert_eq!(datetime.to_rfc3339(), "2022-10-12T08:40:11.695164300+09:00");
+    }
+
+    #[test]
+    fn local() {
+        assert_eq!(
+            DateTimeDefaultFromNow::<Local, ONE_DAY>::default(),
+            clock::now().with_timezone(&Local) + Duration::days(1)
+        );
+    }
+
+    #[test]
+    fn widest_offsets() {
+        assert_eq!(
+            DateTimeDefaultFromNow::<Utc, 8_000_000_000_000>::default().to_rfc3339(),
+            "+255532-09-07T13:53:31.695164300+00:00"
+        );
+        assert_eq!(
+            DateTimeDefaultFromNow::<Utc, -8_000_000_000_000>::default().to_rfc3339(),
+            "-251488-11-13T09:26:51.695164300+00:00"
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{DateTimeWithDefault, Max, Min};
+
+/// 9999-12-31T23:59:59Z, an end of time accepted by most databases.
+///
+/// Use it with `DateTimeDefaultAt` when `DateTimeDefaultMax` is out of the range of the storage.
+///
+/// ```
+/// use chrono::Utc;
+/// use datetime_default::{DateTimeDefaultAt, END_OF_TIME_SECS};
+///
+/// assert_eq!(
+///     DateTimeDefaultAt::<Utc, END_OF_TIME_SECS>::default().to_rfc3339(),
+///     "9999-12-31T23:59:59+00:00"
+/// );
+/// ```
+pub const END_OF_TIME_SECS: i64 = 253_402_300_799;
+
+/// 0001-01-01T00:00:00Z, a beginning of time accepted by most databases.
+pub const BEGINNING_OF_TIME_SECS: i64 = -62_135_596_800;
+
+/// # DateTime with the latest representable time as default.
+///
+/// The local time is `NaiveDateTime::MAX` for zones east of UTC,
+/// so shifting it by the offset never overflows.
+///
+/// ```
+/// use chrono::{FixedOffset, NaiveDateTime};
+/// use datetime_default::DateTimeDefaultMax;
+///
+/// let valid_to = DateTimeDefaultMax::<FixedOffset, 9>::default();
+///
+/// assert_eq!(valid_to.naive_local(), NaiveDateTime::MAX);
+/// ```
+pub type DateTimeDefaultMax<Tz, const OFFSET_HOURS: i32 = 0, const OFFSET_MINUTES: i32 = 0> =
+    DateTimeWithDefault<Tz, Max, OFFSET_HOURS, OFFSET_MINUTES>;
+
+/// # DateTime with the earliest representable time as default.
+///
+/// The local time is `NaiveDateTime::MIN` for zones west of UTC,
+/// so shifting it by the offset never overflows.
+pub type DateTimeDefaultMin<Tz, const OFFSET_HOURS: i32 = 0, const OFFSET_MINUTES: i32 = 0> =
+    DateTimeWithDefault<Tz, Min, OFFSET_HOURS, OFFSET_MINUTES>;
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, Utc};
+
+    use crate::{
+        DateTimeDefaultAt, DateTimeDefaultMax, DateTimeDefaultMin, BEGINNING_OF_TIME_SECS,
+    };
+
+    #[derive(Default)]
+    struct Validity {
+        valid_from: DateTimeDefaultMin<Utc>,
+        valid_to: DateTimeDefaultMax<Utc>,
+    }
+
+    #[test]
+    fn use_default_derive_struct() {
+        let validity = Validity::default();
+
+        assert_eq!(validity.valid_from, DateTime::<Utc>::MIN_UTC);
+        assert_eq!(validity.valid_to, DateTime::<Utc>::MAX_UTC);
+    }
+
+    #[test]
+    fn fixed_offset_east() {
+        let valid_from = DateTimeDefaultMin::<FixedOffset, 9>::default();
+        let valid_to = DateTimeDefaultMax::<FixedOffset, 9>::default();
+
+        assert_eq!(*valid_from, DateTime::<Utc>::MIN_UTC);
+        assert_eq!(valid_to.naive_local(), NaiveDateTime::MAX);
+        assert_eq!(
+            valid_to.to_rfc3339(),
+            "+262143-12-31T23:59:59.999999999+09:00"
+        );
+    }
+
+    #[test]
+    fn fixed_offset_west() {
+        let valid_from = DateTimeDefaultMin::<FixedOffset, -5, -30>::default();
+        let valid_to = DateTimeDefaultMax::<FixedOffset, -5, -30>::default();
+
+        assert_eq!(valid_from.naive_local(), NaiveDateTime::MIN);
+        assert_eq!(valid_from.to_string(), "-262144-01-01 00:00:00 -05:30");
+        assert_eq!(*valid_to, DateTime::<Utc>::MAX_UTC);
+    }
+
+    #[test]
+    fn local() {
+        DateTimeDefaultMax::<Local>::default().to_rfc3339();
+        DateTimeDefaultMin::<Local>::default().to_rfc3339();
+    }
+
+    #[test]
+    fn beginning_of_time() {
+        assert_eq!(
+            DateTimeDefaultAt::<Utc, BEGINNING_OF_TIME_SECS>::default().to_rfc3339(),
+            "0001-01-01T00:00:00+00:00"
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{DateTimeWithDefault, NowTruncated};
+
+/// # DateTime with current time truncated to `SUBSEC_DIGITS` fractional digits as default.
+///
+/// Use 0, 3 or 6 digits to keep values equal after a round trip
+/// through storage with seconds, milliseconds or microseconds precision.
+///
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use datetime_default::{clock, DateTimeDefaultNowTruncated};
+///
+/// let now = DateTime::parse_from_rfc3339("2022-10-10T23:40:11.695164300Z").unwrap();
+///
+/// clock::with_frozen_now(now, || {
+///     assert_eq!(
+///         DateTimeDefaultNowTruncated::<Utc, 6>::default().to_rfc3339(),
+///         "2022-10-10T23:40:11.695164+00:00"
+///     );
+/// });
+/// ```
+pub type DateTimeDefaultNowTruncated<
+    Tz,
+    const SUBSEC_DIGITS: u16,
+    const OFFSET_HOURS: i32 = 0,
+    const OFFSET_MINUTES: i32 = 0,
+> = DateTimeWithDefault<Tz, NowTruncated<SUBSEC_DIGITS>, OFFSET_HOURS, OFFSET_MINUTES>;
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::{FixedOffset, Local, SubsecRound, TimeZone, Utc};
+
+    use crate::{clock, DateTimeDefaultNowTruncated};
+
+    #[derive(Default)]
+    struct Test {
+        secs: DateTimeDefaultNowTruncated<Utc, 0>,
+        millis: DateTimeDefaultNowTruncated<Utc, 3>,
+        micros: DateTimeDefaultNowTruncated<Utc, 6>,
+        nanos: DateTimeDefaultNowTruncated<Utc, 9>,
+    }
+
+    #[test]
+    fn use_default_derive_struct() {
+        let test = Test::default();
+
+        assert_eq!(test.secs.to_rfc3339(), "2022-10-10T23:40:11+00:00");
+        assert_eq!(test.millis.to_rfc3339(), "2022-10-10T23:40:11.695+00:00");
+        assert_eq!(test.micros.to_rfc3339(), "2022-10-10T23:40:11.695164+00:00");
+        assert_eq!(
+            test.nanos.to_rfc3339(),
+            "2022-10-10T23:40:11.695164300+00:00"
+        );
+    }
+
+    #[test]
+    fn fixed_offset() {
+        let datetime = DateTimeDefaultNowTruncated::<FixedOffset, 3, 9>::default();
+
+        assert_eq!(datetime.to_rfc3339(), "2022-10-11T08:40:11.695+09:00");
+    }
+
+    #[test]
+    fn local() {
+        assert_eq!(
+            DateTimeDefaultNowTruncated::<Local, 0>::default(),
+            clock::now().with_timezone(&Local).trunc_subsecs(0)
+        );
+    }
+
+    #[test]
+    fn round_trip_millis() {
+        let datetime = DateTimeDefaultNowTruncated::<Utc, 3>::default();
+        let millis = Utc
+            .timestamp_millis_opt(datetime.timestamp_millis())
+            .unwrap();
+
+        assert_eq!(datetime, millis);
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{DateTimeWithDefault, StartOfDay, StartOfMonth, StartOfWeek, StartOfYear};
+
+/// # DateTime with the start of the current day as default.
+///
+/// The day is the one of the current time in the time zone of the NewType,
+/// so it may differ from the day in UTC.
+///
+/// ```
+/// use chrono::{DateTime, FixedOffset, Utc};
+/// use datetime_default::{clock, DateTimeDefaultStartOfDay};
+///
+/// let now = DateTime::parse_from_rfc3339("2022-10-10T23:40:11Z").unwrap();
+///
+/// clock::with_frozen_now(now, || {
+///     assert_eq!(
+///         DateTimeDefaultStartOfDay::<Utc>::default().to_rfc3339(),
+///         "2022-10-10T00:00:00+00:00"
+///     );
+///     assert_eq!(
+///         DateTimeDefaultStartOfDay::<FixedOffset, 9>::default().to_rfc3339(),
+///         "2022-10-11T00:00:00+09:00"
+///     );
+/// });
+/// ```
+pub type DateTimeDefaultStartOfDay<Tz, const OFFSET_HOURS: i32 = 0, const OFFSET_MINUTES: i32 = 0> =
+    DateTimeWithDefault<Tz, StartOfDay, OFFSET_HOURS, OFFSET_MINUTES>;
+
+/// # DateTime with the start of the current week as default.
+///
+/// `FIRST_WEEKDAY` is the number of days from Monday of the first day of the week.
+/// The default 0 is the ISO week, and 6 starts the week on Sunday.
+///
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use datetime_default::{clock, DateTimeDefaultStartOfWeek};
+///
+/// // Wednesday
+/// let now = DateTime::parse_from_rfc3339("2022-10-12T12:00:00Z").unwrap();
+///
+/// clock::with_frozen_now(now, || {
+///     assert_eq!(
+///         DateTimeDefaultStartOfWeek::<Utc>::default().to_rfc3339(),
+///         "2022-10-10T00:00:00+00:00"
+///     );
+///     assert_eq!(
+///         DateTimeDefaultStartOfWeek::<Utc, 6>::default().to_rfc3339(),
+///         "2022-10-09T00:00:00+00:00"
+///     );
+/// });
+/// ```
+pub type DateTimeDefaultStartOfWeek<
+    Tz,
+    const FIRST_WEEKDAY: u8 = 0,
+    const OFFSET_HOURS: i32 = 0,
+    const OFFSET_MINUTES: i32 = 0,
+> = DateTimeWithDefault<Tz, StartOfWeek<FIRST_WEEKDAY>, OFFSET_HOURS, OFFSET_MINUTES>;
+
+/// # DateTime with the start of the current month as default.
+pub type DateTimeDefaultStartOfMonth<
+    Tz,
+    const OFFSET_HOURS: i32 = 0,
+    const OFFSET_MINUTES: i32 = 0,
+> = DateTimeWithDefault<Tz, StartOfMonth, OFFSET_HOURS, OFFSET_MINUTES>;
+
+/// # DateTime with the start of the current year as default.
+pub type DateTimeDefaultStartOfYear<
+    Tz,
+    const OFFSET_HOURS: i32 = 0,
+    const OFFSET_MINUTES: i32 = 0,
+> = DateTimeWithDefault<Tz, StartOfYear, OFFSET_HOURS, OFFSET_MINUTES>;
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::{DateTime, Datelike, FixedOffset, Local, Utc};
+
+    use crate::{
+        clock, DateTimeDefaultStartOfDay, DateTimeDefaultStartOfMonth, DateTimeDefaultStartOfWeek,
+        DateTimeDefaultStartOfYear,
+    };
+
+    #[derive(Default)]
+    struct Report {
+        day: DateTimeDefaultStartOfDay<Utc>,
+        week: DateTimeDefaultStartOfWeek<Utc>,
+        month: DateTimeDefaultStartOfMonth<Utc>,
+        year: DateTimeDefaultStartOfYear<Utc>,
+    }
+
+    #[test]
+    fn use_default_derive_struct() {
+        let report = Report::default();
+
+        assert_eq!(report.day.to_rfc3339(), "2022-10-10T00:00:00+00:00");
+        assert_eq!(report.week.to_rfc3339(), "2022-10-10T00:00:00+00:00");
+        assert_eq!(report.month.to_rfc3339(), "2022-10-01T00:00:00+00:00");
+        assert_eq!(report.year.to_rfc3339(), "2022-01-01T00:00:00+00:00");
+    }
+
+    #[test]
+    fn fixed_offset() {
+        assert_eq!(
+            DateTimeDefaultStartOfDay::<FixedOffset, 9>::default().to_rfc3339(),
+            "2022-10-11T00:00:00+09:00"
+        );
+        assert_eq!(
+            DateTimeDefaultStartOfDay::<FixedOffset, -5, -30>::default().to_rfc3339(),
+            "2022-10-10T00:00:00-05:30"
+        );
+    }
+
+    #[test]
+    fn first_weekday() {
+        assert_eq!(
+            DateTimeDefaultStartOfWeek::<Utc, 1>::default().to_rfc3339(),
+            "2022-10-04T00:00:00+00:00"
+        );
+        assert_eq!(
+            DateTimeDefaultStartOfWeek::<Utc, 6>::default().to_rfc3339(),
+            "2022-10-09T00:00:00+00:00"
+        );
+        // Tuesday in +09:00
+        assert_eq!(
+            DateTimeDefaultStartOfWeek::<FixedOffset, 1, 9>::default().to_rfc3339(),
+            "2022-10-11T00:00:00+09:00"
+        );
+    }
+
+    #[test]
+    fn new_year() {
+        let now = DateTime::parse_from_rfc3339("2022-12-31T20:00:00Z").unwrap();
+
+        clock::with_frozen_now(now, || {
+            assert_eq!(
+                DateTimeDefaultStartOfYear::<Utc>::default().to_rfc3339(),
+                "2022-01-01T00:00:00+00:00"
+            );
+            assert_eq!(
+                DateTimeDefaultStartOfYear::<FixedOffset, 9>::default().to_rfc3339(),
+                "2023-01-01T00:00:00+09:00"
+            );
+        });
+    }
+
+    #[test]
+    fn local() {
+        let today = clock::now().with_timezone(&Local).naive_local().date();
+        let start_of_day = DateTimeDefaultStartOfDay::<Local>::default();
+
+        assert_eq!(start_of_day.naive_local().date(), today);
+        assert!(start_of_day.with_timezone(&Utc) <= clock::now());
+        assert_eq!(
+            DateTimeDefaultStartOfMonth::<Local>::default()
+                .naive_local()
+                .date(),
+            today.with_day(1).unwrap()
+        );
+    }
+}
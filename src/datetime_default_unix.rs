@@ -1,8 +1,4 @@
-use std::ops::Deref;
-
-use chrono::{DateTime, Duration, FixedOffset, Local, TimeZone, Utc};
-
-use crate::const_assert::AssertOffsetHours;
+use crate::{DateTimeWithDefault, Unix};
 
 /// # DateTime with UNIX epoch as default.
 ///
@@ -15,388 +11,8 @@ use crate::const_assert::AssertOffsetHours;
 ///     Utc.datetime_from_str("1970/1/1 00:00:00", "%Y/%m/%d %H:%M:%S").unwrap()
 /// );
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct DateTimeDefaultUnix<Tz, const OFFSET_HOURS: i32 = 0>(DateTime<Tz>)
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy;
-
-impl<const OFFSET_HOURS: i32> Default for DateTimeDefaultUnix<FixedOffset, OFFSET_HOURS> {
-    #[allow(path_statements)]
-    #[allow(clippy::no_effect)]
-    fn default() -> Self {
-        AssertOffsetHours::<-24, OFFSET_HOURS, 24>::OK;
-
-        Self(
-            DateTimeDefaultUnix::<Utc>::default()
-                .with_timezone(&FixedOffset::east_opt(OFFSET_HOURS * 3600).unwrap()),
-        )
-    }
-}
-
-impl Default for DateTimeDefaultUnix<Local, 0> {
-    fn default() -> Self {
-        Self(DateTime::<Local>::default())
-    }
-}
-
-impl Default for DateTimeDefaultUnix<Utc, 0> {
-    fn default() -> Self {
-        Self(DateTime::<Utc>::default())
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> Deref for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    type Target = DateTime<Tz>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> From<DateTime<Tz>> for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    fn from(datetime: DateTime<Tz>) -> Self {
-        Self(datetime)
-    }
-}
-
-impl<const OFFSET_HOURS: i32> std::str::FromStr for DateTimeDefaultUnix<FixedOffset, OFFSET_HOURS> {
-    type Err = chrono::ParseError;
-
-    fn from_str(s: &str) -> chrono::ParseResult<DateTimeDefaultUnix<FixedOffset, OFFSET_HOURS>> {
-        DateTime::<FixedOffset>::from_str(s).map(DateTimeDefaultUnix::from)
-    }
-}
-
-impl<const OFFSET_HOURS: i32> std::str::FromStr for DateTimeDefaultUnix<Local, OFFSET_HOURS> {
-    type Err = chrono::ParseError;
-
-    fn from_str(s: &str) -> chrono::ParseResult<DateTimeDefaultUnix<Local, OFFSET_HOURS>> {
-        DateTime::<Local>::from_str(s).map(DateTimeDefaultUnix::from)
-    }
-}
-
-impl<const OFFSET_HOURS: i32> std::str::FromStr for DateTimeDefaultUnix<Utc, OFFSET_HOURS> {
-    type Err = chrono::ParseError;
-
-    fn from_str(s: &str) -> chrono::ParseResult<DateTimeDefaultUnix<Utc, OFFSET_HOURS>> {
-        DateTime::<Utc>::from_str(s).map(DateTimeDefaultUnix::from)
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> std::cmp::PartialEq<DateTime<Tz>>
-    for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    fn eq(&self, other: &DateTime<Tz>) -> bool {
-        self.0.eq(other)
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> std::cmp::PartialEq<DateTimeDefaultUnix<Tz, OFFSET_HOURS>>
-    for DateTime<Tz>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    fn eq(&self, other: &DateTimeDefaultUnix<Tz, OFFSET_HOURS>) -> bool {
-        self.eq(&other.0)
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> std::cmp::PartialOrd<DateTime<Tz>>
-    for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    fn partial_cmp(&self, other: &DateTime<Tz>) -> Option<std::cmp::Ordering> {
-        self.0.partial_cmp(other)
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> std::cmp::PartialOrd<DateTimeDefaultUnix<Tz, OFFSET_HOURS>>
-    for DateTime<Tz>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    fn partial_cmp(
-        &self,
-        other: &DateTimeDefaultUnix<Tz, OFFSET_HOURS>,
-    ) -> Option<std::cmp::Ordering> {
-        self.partial_cmp(&other.0)
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Add<Duration> for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    type Output = DateTimeDefaultUnix<Tz, OFFSET_HOURS>;
-
-    #[inline]
-    fn add(self, rhs: Duration) -> Self::Output {
-        DateTimeDefaultUnix(self.0.add(rhs))
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Add<FixedOffset>
-    for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    type Output = DateTimeDefaultUnix<Tz, OFFSET_HOURS>;
-
-    #[inline]
-    fn add(self, rhs: FixedOffset) -> Self::Output {
-        DateTimeDefaultUnix(self.0.add(rhs))
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> std::ops::AddAssign<Duration>
-    for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    #[inline]
-    fn add_assign(&mut self, rhs: Duration) {
-        self.0.add_assign(rhs);
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Sub<DateTimeDefaultUnix<Tz, OFFSET_HOURS>>
-    for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    type Output = Duration;
-
-    #[inline]
-    fn sub(self, rhs: DateTimeDefaultUnix<Tz, OFFSET_HOURS>) -> Duration {
-        self.0.sub(rhs.0)
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Sub<DateTime<Tz>>
-    for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    type Output = Duration;
-
-    #[inline]
-    fn sub(self, rhs: DateTime<Tz>) -> Duration {
-        self.0.sub(rhs)
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Sub<Duration> for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    type Output = DateTimeDefaultUnix<Tz, OFFSET_HOURS>;
-
-    #[inline]
-    fn sub(self, rhs: Duration) -> Self::Output {
-        DateTimeDefaultUnix(self.0.sub(rhs))
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Sub<FixedOffset>
-    for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    type Output = DateTimeDefaultUnix<Tz, OFFSET_HOURS>;
-
-    #[inline]
-    fn sub(self, rhs: FixedOffset) -> Self::Output {
-        DateTimeDefaultUnix(self.0.sub(rhs))
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> std::ops::SubAssign<Duration>
-    for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    #[inline]
-    fn sub_assign(&mut self, rhs: Duration) {
-        self.0.sub_assign(rhs);
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> chrono::DurationRound for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    type Err = chrono::RoundingError;
-
-    fn duration_round(self, duration: Duration) -> Result<Self, Self::Err> {
-        self.0
-            .duration_round(duration)
-            .map(DateTimeDefaultUnix::from)
-    }
-
-    fn duration_trunc(self, duration: Duration) -> Result<Self, Self::Err> {
-        self.0
-            .duration_trunc(duration)
-            .map(DateTimeDefaultUnix::from)
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> chrono::Datelike for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    #[inline]
-    fn year(&self) -> i32 {
-        self.0.year()
-    }
-    #[inline]
-    fn month(&self) -> u32 {
-        self.0.month()
-    }
-    #[inline]
-    fn month0(&self) -> u32 {
-        self.0.month0()
-    }
-    #[inline]
-    fn day(&self) -> u32 {
-        self.0.day()
-    }
-    #[inline]
-    fn day0(&self) -> u32 {
-        self.0.day0()
-    }
-    #[inline]
-    fn ordinal(&self) -> u32 {
-        self.0.ordinal()
-    }
-    #[inline]
-    fn ordinal0(&self) -> u32 {
-        self.0.ordinal0()
-    }
-    #[inline]
-    fn weekday(&self) -> chrono::Weekday {
-        self.0.weekday()
-    }
-    #[inline]
-    fn iso_week(&self) -> chrono::IsoWeek {
-        self.0.iso_week()
-    }
-
-    #[inline]
-    fn with_year(&self, year: i32) -> Option<DateTimeDefaultUnix<Tz, OFFSET_HOURS>> {
-        self.0.with_year(year).map(DateTimeDefaultUnix::from)
-    }
-
-    #[inline]
-    fn with_month(&self, month: u32) -> Option<DateTimeDefaultUnix<Tz, OFFSET_HOURS>> {
-        self.0.with_month(month).map(DateTimeDefaultUnix::from)
-    }
-
-    #[inline]
-    fn with_month0(&self, month0: u32) -> Option<DateTimeDefaultUnix<Tz, OFFSET_HOURS>> {
-        self.0.with_month0(month0).map(DateTimeDefaultUnix::from)
-    }
-
-    #[inline]
-    fn with_day(&self, day: u32) -> Option<DateTimeDefaultUnix<Tz, OFFSET_HOURS>> {
-        self.0.with_day(day).map(DateTimeDefaultUnix::from)
-    }
-
-    #[inline]
-    fn with_day0(&self, day0: u32) -> Option<DateTimeDefaultUnix<Tz, OFFSET_HOURS>> {
-        self.0.with_day0(day0).map(DateTimeDefaultUnix::from)
-    }
-
-    #[inline]
-    fn with_ordinal(&self, ordinal: u32) -> Option<DateTimeDefaultUnix<Tz, OFFSET_HOURS>> {
-        self.0.with_ordinal(ordinal).map(DateTimeDefaultUnix::from)
-    }
-
-    #[inline]
-    fn with_ordinal0(&self, ordinal0: u32) -> Option<DateTimeDefaultUnix<Tz, OFFSET_HOURS>> {
-        self.0
-            .with_ordinal0(ordinal0)
-            .map(DateTimeDefaultUnix::from)
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> chrono::Timelike for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
-{
-    #[inline]
-    fn hour(&self) -> u32 {
-        self.0.hour()
-    }
-    #[inline]
-    fn minute(&self) -> u32 {
-        self.0.minute()
-    }
-    #[inline]
-    fn second(&self) -> u32 {
-        self.0.second()
-    }
-    #[inline]
-    fn nanosecond(&self) -> u32 {
-        self.0.nanosecond()
-    }
-
-    #[inline]
-    fn with_hour(&self, hour: u32) -> Option<DateTimeDefaultUnix<Tz, OFFSET_HOURS>> {
-        self.0.with_hour(hour).map(DateTimeDefaultUnix::from)
-    }
-
-    #[inline]
-    fn with_minute(&self, min: u32) -> Option<DateTimeDefaultUnix<Tz, OFFSET_HOURS>> {
-        self.0.with_minute(min).map(DateTimeDefaultUnix::from)
-    }
-
-    #[inline]
-    fn with_second(&self, sec: u32) -> Option<DateTimeDefaultUnix<Tz, OFFSET_HOURS>> {
-        self.0.with_second(sec).map(DateTimeDefaultUnix::from)
-    }
-
-    #[inline]
-    fn with_nanosecond(&self, nano: u32) -> Option<DateTimeDefaultUnix<Tz, OFFSET_HOURS>> {
-        self.0.with_nanosecond(nano).map(DateTimeDefaultUnix::from)
-    }
-}
-
-impl<Tz, const OFFSET_HOURS: i32> std::fmt::Display for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy + std::fmt::Display,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.0.fmt(f)
-    }
-}
+pub type DateTimeDefaultUnix<Tz, const OFFSET_HOURS: i32 = 0, const OFFSET_MINUTES: i32 = 0> =
+    DateTimeWithDefault<Tz, Unix, OFFSET_HOURS, OFFSET_MINUTES>;
 
 #[cfg(test)]
 mod tests {
@@ -450,6 +66,20 @@ mod tests {
         assert_eq!(datetime.to_rfc3339(), "1970-01-01T09:00:00+09:00");
     }
 
+    #[test]
+    fn fixed_offset_minutes() {
+        let datetime = DateTimeDefaultUnix::<FixedOffset, 5, 45>::default();
+
+        assert_eq!(datetime.to_rfc3339(), "1970-01-01T05:45:00+05:45");
+    }
+
+    #[test]
+    fn negative_fixed_offset_minutes() {
+        let datetime = DateTimeDefaultUnix::<FixedOffset, -3, -30>::default();
+
+        assert_eq!(datetime.to_rfc3339(), "1969-12-31T20:30:00-03:30");
+    }
+
     #[test]
     fn use_datetime_method() {
         let datetime = DateTimeDefaultUnix::<Utc>::default();
@@ -0,0 +1,1114 @@
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    hash::Hash,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    str::FromStr,
+};
+
+use chrono::{DateTime, Duration, FixedOffset, Local, Offset, TimeZone, Utc};
+
+use crate::{
+    const_assert::{AssertOffsetHours, AssertOffsetMinutes, AssertZeroOffset},
+    DefaultProvider, FromUtc, Now, Unix,
+};
+
+/// # DateTime with the default given by a [`DefaultProvider`].
+///
+/// `DateTimeDefaultNow`, `DateTimeDefaultUnix` and `DateTimeDefaultAt` are aliases of this type.
+/// The provider gets the time zone of the NewType, so the default may depend on it.
+///
+/// ```
+/// use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
+/// use datetime_default::{DateTimeWithDefault, DefaultProvider};
+///
+/// /// Midnight of 2000-01-01 in the time zone of the NewType.
+/// struct LocalY2K;
+///
+/// impl DefaultProvider for LocalY2K {
+///     fn default_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz> {
+///         let y2k = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+///
+///         tz.from_local_datetime(&y2k).unwrap()
+///     }
+/// }
+///
+/// assert_eq!(
+///     DateTimeWithDefault::<FixedOffset, LocalY2K, 9>::default().to_rfc3339(),
+///     "2000-01-01T00:00:00+09:00"
+/// );
+/// ```
+///
+/// `OFFSET_HOURS` and `OFFSET_MINUTES` only apply to `FixedOffset`.
+/// Other time zones reject non-zero offsets at compile time when parsing or converting.
+///
+/// ```compile_fail
+/// use chrono::Utc;
+/// use datetime_default::DateTimeDefaultNow;
+///
+/// let _ = "2022-01-01T00:00:00Z".parse::<DateTimeDefaultNow<Utc, 9>>();
+/// ```
+pub struct DateTimeWithDefault<Tz, P, const OFFSET_HOURS: i32 = 0, const OFFSET_MINUTES: i32 = 0>(
+    DateTime<Tz>,
+    PhantomData<P>,
+)
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy;
+
+impl<P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Default
+    for DateTimeWithDefault<FixedOffset, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    P: DefaultProvider,
+{
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn default() -> Self {
+        AssertOffsetHours::<-24, OFFSET_HOURS, 24>::OK;
+        AssertOffsetMinutes::<OFFSET_HOURS, OFFSET_MINUTES>::OK;
+
+        Self::from_inner(P::default_in(
+            &FixedOffset::east_opt(OFFSET_HOURS * 3600 + OFFSET_MINUTES * 60).unwrap(),
+        ))
+    }
+}
+
+impl<P> Default for DateTimeWithDefault<Local, P, 0, 0>
+where
+    P: DefaultProvider,
+{
+    fn default() -> Self {
+        Self::from_inner(P::default_in(&Local))
+    }
+}
+
+impl<P> Default for DateTimeWithDefault<Utc, P, 0, 0>
+where
+    P: DefaultProvider,
+{
+    fn default() -> Self {
+        Self::from_inner(P::default_in(&Utc))
+    }
+}
+
+// The derives would require the provider to implement the traits too.
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::fmt::Debug
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("DateTimeWithDefault").field(&self.0).finish()
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Clone
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Copy
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+}
+
+// Like `DateTime`, NewTypes in different zones are equal when they are the same instant.
+impl<
+        Tz,
+        Tz2,
+        P,
+        P2,
+        const OFFSET_HOURS: i32,
+        const OFFSET_MINUTES: i32,
+        const OFFSET_HOURS2: i32,
+        const OFFSET_MINUTES2: i32,
+    > PartialEq<DateTimeWithDefault<Tz2, P2, OFFSET_HOURS2, OFFSET_MINUTES2>>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Tz2: TimeZone,
+    <Tz2 as TimeZone>::Offset: Copy,
+{
+    fn eq(&self, other: &DateTimeWithDefault<Tz2, P2, OFFSET_HOURS2, OFFSET_MINUTES2>) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Eq
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+}
+
+impl<
+        Tz,
+        Tz2,
+        P,
+        P2,
+        const OFFSET_HOURS: i32,
+        const OFFSET_MINUTES: i32,
+        const OFFSET_HOURS2: i32,
+        const OFFSET_MINUTES2: i32,
+    > PartialOrd<DateTimeWithDefault<Tz2, P2, OFFSET_HOURS2, OFFSET_MINUTES2>>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Tz2: TimeZone,
+    <Tz2 as TimeZone>::Offset: Copy,
+{
+    fn partial_cmp(
+        &self,
+        other: &DateTimeWithDefault<Tz2, P2, OFFSET_HOURS2, OFFSET_MINUTES2>,
+    ) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Ord
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Hash
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Deref
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Target = DateTime<Tz>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> From<DateTime<Tz>>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Self: FromUtc,
+{
+    /// Converts into the time zone of the NewType, e.g. to the offset of a `FixedOffset` NewType.
+    fn from(datetime: DateTime<Tz>) -> Self {
+        Self::from_utc(&datetime.with_timezone(&Utc))
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    /// Wraps `datetime` as is, which must already be in the time zone of the NewType.
+    pub(crate) fn from_inner(datetime: DateTime<Tz>) -> Self {
+        Self(datetime, PhantomData)
+    }
+
+    /// Unwraps the inner `DateTime<Tz>`.
+    ///
+    /// ```
+    /// use chrono::{DateTime, Utc};
+    /// use datetime_default::DateTimeDefaultUnix;
+    ///
+    /// let datetime: DateTime<Utc> = DateTimeDefaultUnix::<Utc>::default().into_inner();
+    ///
+    /// assert_eq!(datetime.timestamp(), 0);
+    /// ```
+    pub fn into_inner(self) -> DateTime<Tz> {
+        self.0
+    }
+
+    /// Converts into the NewType of the same provider in `UTC`.
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    /// use datetime_default::DateTimeDefaultUnix;
+    ///
+    /// let tokyo = DateTimeDefaultUnix::<FixedOffset, 9>::default();
+    ///
+    /// assert_eq!(tokyo.to_utc().to_rfc3339(), "1970-01-01T00:00:00+00:00");
+    /// assert_eq!(tokyo.to_utc(), tokyo);
+    /// ```
+    pub fn to_utc(&self) -> DateTimeWithDefault<Utc, P> {
+        DateTimeWithDefault::from_utc(&self.0.with_timezone(&Utc))
+    }
+
+    /// Converts into the NewType of the same provider with the offset of `OFFSET_HOURS2` hours.
+    ///
+    /// ```
+    /// use chrono::Utc;
+    /// use datetime_default::DateTimeDefaultUnix;
+    ///
+    /// let tokyo = DateTimeDefaultUnix::<Utc>::default().with_offset::<9>();
+    ///
+    /// assert_eq!(tokyo.to_rfc3339(), "1970-01-01T09:00:00+09:00");
+    /// ```
+    pub fn with_offset<const OFFSET_HOURS2: i32>(
+        &self,
+    ) -> DateTimeWithDefault<FixedOffset, P, OFFSET_HOURS2> {
+        self.with_offset_hm::<OFFSET_HOURS2, 0>()
+    }
+
+    /// Converts into the NewType of the same provider
+    /// with the offset of `OFFSET_HOURS2` hours and `OFFSET_MINUTES2` minutes.
+    ///
+    /// ```
+    /// use chrono::Utc;
+    /// use datetime_default::DateTimeDefaultUnix;
+    ///
+    /// let kolkata = DateTimeDefaultUnix::<Utc>::default().with_offset_hm::<5, 30>();
+    ///
+    /// assert_eq!(kolkata.to_rfc3339(), "1970-01-01T05:30:00+05:30");
+    /// ```
+    pub fn with_offset_hm<const OFFSET_HOURS2: i32, const OFFSET_MINUTES2: i32>(
+        &self,
+    ) -> DateTimeWithDefault<FixedOffset, P, OFFSET_HOURS2, OFFSET_MINUTES2> {
+        DateTimeWithDefault::from_utc(&self.0.with_timezone(&Utc))
+    }
+}
+
+// Assigning through `DerefMut` or `AsMut` stores the value as is; `set` converts it.
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> DerefMut
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Self: FromUtc,
+{
+    /// Replaces the value, converting `datetime` into the time zone of the NewType.
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset};
+    /// use datetime_default::DateTimeDefaultUnix;
+    ///
+    /// let mut tokyo = DateTimeDefaultUnix::<FixedOffset, 9>::default();
+    /// tokyo.set(DateTime::parse_from_rfc3339("2022-01-01T00:00:00Z").unwrap());
+    ///
+    /// assert_eq!(tokyo.to_rfc3339(), "2022-01-01T09:00:00+09:00");
+    /// ```
+    pub fn set<Tz2: TimeZone>(&mut self, datetime: DateTime<Tz2>) {
+        *self = Self::from_utc(&datetime.with_timezone(&Utc));
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> AsRef<DateTime<Tz>>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn as_ref(&self) -> &DateTime<Tz> {
+        &self.0
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> AsMut<DateTime<Tz>>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn as_mut(&mut self) -> &mut DateTime<Tz> {
+        &mut self.0
+    }
+}
+
+// `Eq`, `Ord` and `Hash` delegate to the inner `DateTime<Tz>`, as `Borrow` requires.
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Borrow<DateTime<Tz>>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn borrow(&self) -> &DateTime<Tz> {
+        &self.0
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    From<DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> for DateTime<Tz>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn from(datetime: DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>) -> Self {
+        datetime.0
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    From<DateTimeWithDefault<Tz, Now, OFFSET_HOURS, OFFSET_MINUTES>>
+    for DateTimeWithDefault<Tz, Unix, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn from(datetime: DateTimeWithDefault<Tz, Now, OFFSET_HOURS, OFFSET_MINUTES>) -> Self {
+        Self::from_inner(datetime.0)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    From<DateTimeWithDefault<Tz, Unix, OFFSET_HOURS, OFFSET_MINUTES>>
+    for DateTimeWithDefault<Tz, Now, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn from(datetime: DateTimeWithDefault<Tz, Unix, OFFSET_HOURS, OFFSET_MINUTES>) -> Self {
+        Self::from_inner(datetime.0)
+    }
+}
+
+impl<P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromStr
+    for DateTimeWithDefault<FixedOffset, P, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    type Err = chrono::ParseError;
+
+    /// Parses like `DateTime<FixedOffset>`, then converts into the offset of the NewType.
+    fn from_str(
+        s: &str,
+    ) -> chrono::ParseResult<DateTimeWithDefault<FixedOffset, P, OFFSET_HOURS, OFFSET_MINUTES>>
+    {
+        DateTime::<FixedOffset>::from_str(s)
+            .map(|datetime| Self::from_utc(&datetime.with_timezone(&Utc)))
+    }
+}
+
+impl<P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromStr
+    for DateTimeWithDefault<Local, P, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    type Err = chrono::ParseError;
+
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn from_str(
+        s: &str,
+    ) -> chrono::ParseResult<DateTimeWithDefault<Local, P, OFFSET_HOURS, OFFSET_MINUTES>> {
+        AssertZeroOffset::<OFFSET_HOURS, OFFSET_MINUTES>::OK;
+
+        DateTime::<Local>::from_str(s).map(DateTimeWithDefault::from_inner)
+    }
+}
+
+impl<P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromStr
+    for DateTimeWithDefault<Utc, P, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    type Err = chrono::ParseError;
+
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn from_str(
+        s: &str,
+    ) -> chrono::ParseResult<DateTimeWithDefault<Utc, P, OFFSET_HOURS, OFFSET_MINUTES>> {
+        AssertZeroOffset::<OFFSET_HOURS, OFFSET_MINUTES>::OK;
+
+        DateTime::<Utc>::from_str(s).map(DateTimeWithDefault::from_inner)
+    }
+}
+
+/// Error of [`DateTimeWithDefault::from_str_strict`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseStrictError {
+    /// Not a `DateTime<FixedOffset>`.
+    Invalid(chrono::ParseError),
+    /// The offset differs from the one of the NewType at that time.
+    Offset {
+        expected: FixedOffset,
+        found: FixedOffset,
+    },
+}
+
+impl std::fmt::Display for ParseStrictError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Invalid(err) => err.fmt(f),
+            Self::Offset { expected, found } => {
+                write!(f, "unexpected offset: {found}, expected {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseStrictError {}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Self: FromUtc,
+{
+    /// Parses like `DateTime<FixedOffset>`,
+    /// and rejects inputs whose offset differs from the one of the NewType.
+    ///
+    /// `FromStr` converts such inputs instead.
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    /// use datetime_default::{DateTimeDefaultNow, ParseStrictError};
+    ///
+    /// type Tokyo = DateTimeDefaultNow<FixedOffset, 9>;
+    ///
+    /// assert!(Tokyo::from_str_strict("2022-01-01T09:00:00+09:00").is_ok());
+    /// assert!(matches!(
+    ///     Tokyo::from_str_strict("2022-01-01T00:00:00Z"),
+    ///     Err(ParseStrictError::Offset { .. })
+    /// ));
+    /// assert_eq!(
+    ///     "2022-01-01T00:00:00Z".parse::<Tokyo>().unwrap().to_rfc3339(),
+    ///     "2022-01-01T09:00:00+09:00"
+    /// );
+    /// ```
+    pub fn from_str_strict(s: &str) -> Result<Self, ParseStrictError> {
+        let parsed = DateTime::<FixedOffset>::from_str(s).map_err(ParseStrictError::Invalid)?;
+        let datetime = Self::from_utc(&parsed.with_timezone(&Utc));
+
+        let expected = datetime.offset().fix();
+        let found = *parsed.offset();
+        if expected != found {
+            return Err(ParseStrictError::Offset { expected, found });
+        }
+
+        Ok(datetime)
+    }
+}
+
+impl<P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromUtc
+    for DateTimeWithDefault<FixedOffset, P, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn from_utc(datetime: &DateTime<Utc>) -> Self {
+        AssertOffsetHours::<-24, OFFSET_HOURS, 24>::OK;
+        AssertOffsetMinutes::<OFFSET_HOURS, OFFSET_MINUTES>::OK;
+
+        Self::from_inner(datetime.with_timezone(
+            &FixedOffset::east_opt(OFFSET_HOURS * 3600 + OFFSET_MINUTES * 60).unwrap(),
+        ))
+    }
+}
+
+impl<P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromUtc
+    for DateTimeWithDefault<Local, P, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn from_utc(datetime: &DateTime<Utc>) -> Self {
+        AssertZeroOffset::<OFFSET_HOURS, OFFSET_MINUTES>::OK;
+
+        Self::from_inner(datetime.with_timezone(&Local))
+    }
+}
+
+impl<P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromUtc
+    for DateTimeWithDefault<Utc, P, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn from_utc(datetime: &DateTime<Utc>) -> Self {
+        AssertZeroOffset::<OFFSET_HOURS, OFFSET_MINUTES>::OK;
+
+        Self::from_inner(*datetime)
+    }
+}
+
+impl<Tz, Tz2, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    std::cmp::PartialEq<DateTime<Tz2>> for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Tz2: TimeZone,
+{
+    fn eq(&self, other: &DateTime<Tz2>) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl<Tz, Tz2, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    std::cmp::PartialEq<DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> for DateTime<Tz2>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Tz2: TimeZone,
+{
+    fn eq(&self, other: &DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>) -> bool {
+        self.eq(&other.0)
+    }
+}
+
+impl<Tz, Tz2, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    std::cmp::PartialOrd<DateTime<Tz2>> for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Tz2: TimeZone,
+{
+    fn partial_cmp(&self, other: &DateTime<Tz2>) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl<Tz, Tz2, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    std::cmp::PartialOrd<DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> for DateTime<Tz2>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Tz2: TimeZone,
+{
+    fn partial_cmp(
+        &self,
+        other: &DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>,
+    ) -> Option<std::cmp::Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::ops::Add<Duration>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>;
+
+    #[inline]
+    fn add(self, rhs: Duration) -> Self::Output {
+        DateTimeWithDefault::from_inner(self.0.add(rhs))
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::ops::Add<FixedOffset>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>;
+
+    #[inline]
+    fn add(self, rhs: FixedOffset) -> Self::Output {
+        DateTimeWithDefault::from_inner(self.0.add(rhs))
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::ops::AddAssign<Duration>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Duration) {
+        self.0.add_assign(rhs);
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    std::ops::Sub<DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = Duration;
+
+    #[inline]
+    fn sub(self, rhs: DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>) -> Duration {
+        self.0.sub(rhs.0)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::ops::Sub<DateTime<Tz>>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = Duration;
+
+    #[inline]
+    fn sub(self, rhs: DateTime<Tz>) -> Duration {
+        self.0.sub(rhs)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::ops::Sub<Duration>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>;
+
+    #[inline]
+    fn sub(self, rhs: Duration) -> Self::Output {
+        DateTimeWithDefault::from_inner(self.0.sub(rhs))
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::ops::Sub<FixedOffset>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>;
+
+    #[inline]
+    fn sub(self, rhs: FixedOffset) -> Self::Output {
+        DateTimeWithDefault::from_inner(self.0.sub(rhs))
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::ops::SubAssign<Duration>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Duration) {
+        self.0.sub_assign(rhs);
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> chrono::DurationRound
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Err = chrono::RoundingError;
+
+    fn duration_round(self, duration: Duration) -> Result<Self, Self::Err> {
+        self.0
+            .duration_round(duration)
+            .map(DateTimeWithDefault::from_inner)
+    }
+
+    fn duration_trunc(self, duration: Duration) -> Result<Self, Self::Err> {
+        self.0
+            .duration_trunc(duration)
+            .map(DateTimeWithDefault::from_inner)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> chrono::Datelike
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    #[inline]
+    fn year(&self) -> i32 {
+        self.0.year()
+    }
+    #[inline]
+    fn month(&self) -> u32 {
+        self.0.month()
+    }
+    #[inline]
+    fn month0(&self) -> u32 {
+        self.0.month0()
+    }
+    #[inline]
+    fn day(&self) -> u32 {
+        self.0.day()
+    }
+    #[inline]
+    fn day0(&self) -> u32 {
+        self.0.day0()
+    }
+    #[inline]
+    fn ordinal(&self) -> u32 {
+        self.0.ordinal()
+    }
+    #[inline]
+    fn ordinal0(&self) -> u32 {
+        self.0.ordinal0()
+    }
+    #[inline]
+    fn weekday(&self) -> chrono::Weekday {
+        self.0.weekday()
+    }
+    #[inline]
+    fn iso_week(&self) -> chrono::IsoWeek {
+        self.0.iso_week()
+    }
+
+    #[inline]
+    fn with_year(
+        &self,
+        year: i32,
+    ) -> Option<DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> {
+        self.0.with_year(year).map(DateTimeWithDefault::from_inner)
+    }
+
+    #[inline]
+    fn with_month(
+        &self,
+        month: u32,
+    ) -> Option<DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> {
+        self.0
+            .with_month(month)
+            .map(DateTimeWithDefault::from_inner)
+    }
+
+    #[inline]
+    fn with_month0(
+        &self,
+        month0: u32,
+    ) -> Option<DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> {
+        self.0
+            .with_month0(month0)
+            .map(DateTimeWithDefault::from_inner)
+    }
+
+    #[inline]
+    fn with_day(
+        &self,
+        day: u32,
+    ) -> Option<DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> {
+        self.0.with_day(day).map(DateTimeWithDefault::from_inner)
+    }
+
+    #[inline]
+    fn with_day0(
+        &self,
+        day0: u32,
+    ) -> Option<DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> {
+        self.0.with_day0(day0).map(DateTimeWithDefault::from_inner)
+    }
+
+    #[inline]
+    fn with_ordinal(
+        &self,
+        ordinal: u32,
+    ) -> Option<DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> {
+        self.0
+            .with_ordinal(ordinal)
+            .map(DateTimeWithDefault::from_inner)
+    }
+
+    #[inline]
+    fn with_ordinal0(
+        &self,
+        ordinal0: u32,
+    ) -> Option<DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> {
+        self.0
+            .with_ordinal0(ordinal0)
+            .map(DateTimeWithDefault::from_inner)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> chrono::Timelike
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    #[inline]
+    fn hour(&self) -> u32 {
+        self.0.hour()
+    }
+    #[inline]
+    fn minute(&self) -> u32 {
+        self.0.minute()
+    }
+    #[inline]
+    fn second(&self) -> u32 {
+        self.0.second()
+    }
+    #[inline]
+    fn nanosecond(&self) -> u32 {
+        self.0.nanosecond()
+    }
+
+    #[inline]
+    fn with_hour(
+        &self,
+        hour: u32,
+    ) -> Option<DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> {
+        self.0.with_hour(hour).map(DateTimeWithDefault::from_inner)
+    }
+
+    #[inline]
+    fn with_minute(
+        &self,
+        min: u32,
+    ) -> Option<DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> {
+        self.0.with_minute(min).map(DateTimeWithDefault::from_inner)
+    }
+
+    #[inline]
+    fn with_second(
+        &self,
+        sec: u32,
+    ) -> Option<DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> {
+        self.0.with_second(sec).map(DateTimeWithDefault::from_inner)
+    }
+
+    #[inline]
+    fn with_nanosecond(
+        &self,
+        nano: u32,
+    ) -> Option<DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> {
+        self.0
+            .with_nanosecond(nano)
+            .map(DateTimeWithDefault::from_inner)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::fmt::Display
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::{DateTime, FixedOffset, Local, TimeZone, Utc};
+
+    use crate::{
+        DateTimeDefaultNow, DateTimeDefaultUnix, DateTimeWithDefault, DefaultProvider,
+        ParseStrictError,
+    };
+
+    struct Yesterday;
+
+    impl DefaultProvider for Yesterday {
+        fn default_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz> {
+            (crate::clock::now() - chrono::Duration::days(1)).with_timezone(tz)
+        }
+    }
+
+    #[derive(Default)]
+    struct Test {
+        updated_at: DateTimeWithDefault<Utc, Yesterday>,
+    }
+
+    #[test]
+    fn custom_provider() {
+        let test = Test::default();
+
+        assert_eq!(
+            test.updated_at.to_rfc3339(),
+            "2022-10-09T23:40:11.695164300+00:00"
+        );
+    }
+
+    #[test]
+    fn custom_provider_fixed_offset() {
+        let datetime = DateTimeWithDefault::<FixedOffset, Yesterday, 9>::default();
+
+        assert_eq!(datetime.to_rfc3339(), "2022-10-10T08:40:11.695164300+09:00");
+    }
+
+    #[test]
+    fn custom_provider_local() {
+        assert_eq!(
+            DateTimeWithDefault::<Local, Yesterday>::default(),
+            DateTimeWithDefault::<Utc, Yesterday>::default().with_timezone(&Local)
+        );
+    }
+
+    #[test]
+    fn traits_without_provider_bounds() {
+        let datetime = DateTimeWithDefault::<Utc, Yesterday>::default();
+        let copied = datetime;
+
+        assert_eq!(datetime, copied);
+        assert!(datetime < copied + chrono::Duration::seconds(1));
+        assert_eq!(
+            format!("{datetime:?}"),
+            "DateTimeWithDefault(2022-10-09T23:40:11.695164300Z)"
+        );
+    }
+
+    #[test]
+    fn aliases() {
+        let datetime: DateTimeWithDefault<Utc, crate::Unix> = DateTimeDefaultUnix::<Utc>::default();
+
+        assert_eq!(datetime, DateTime::<Utc>::default());
+    }
+
+    #[test]
+    fn from_str_converts_into_offset() {
+        let datetime: DateTimeDefaultUnix<FixedOffset, 9> = "2022-01-01T00:00:00Z".parse().unwrap();
+
+        assert_eq!(datetime.to_rfc3339(), "2022-01-01T09:00:00+09:00");
+
+        let datetime: DateTimeDefaultUnix<FixedOffset, -5, -30> =
+            "2022-01-01 09:00:00 +09:00".parse().unwrap();
+
+        assert_eq!(datetime.to_rfc3339(), "2021-12-31T18:30:00-05:30");
+    }
+
+    #[test]
+    fn from_str_strict() {
+        type Tokyo = DateTimeDefaultUnix<FixedOffset, 9>;
+
+        assert_eq!(
+            Tokyo::from_str_strict("2022-01-01T09:00:00+09:00")
+                .unwrap()
+                .to_rfc3339(),
+            "2022-01-01T09:00:00+09:00"
+        );
+        assert_eq!(
+            Tokyo::from_str_strict("2022-01-01T00:00:00Z"),
+            Err(ParseStrictError::Offset {
+                expected: FixedOffset::east_opt(9 * 3600).unwrap(),
+                found: FixedOffset::east_opt(0).unwrap(),
+            })
+        );
+        assert!(matches!(
+            Tokyo::from_str_strict("2022-01-01"),
+            Err(ParseStrictError::Invalid(_))
+        ));
+        assert!(DateTimeDefaultUnix::<Utc>::from_str_strict("2022-01-01T00:00:00Z").is_ok());
+        assert!(DateTimeDefaultUnix::<Utc>::from_str_strict("2022-01-01T09:00:00+09:00").is_err());
+    }
+
+    #[test]
+    fn into_inner() {
+        let datetime = DateTimeDefaultUnix::<FixedOffset, 9>::default();
+        let inner: DateTime<FixedOffset> = datetime.into();
+
+        assert_eq!(datetime.into_inner(), inner);
+        assert_eq!(inner.to_rfc3339(), "1970-01-01T09:00:00+09:00");
+    }
+
+    #[test]
+    fn mutate_in_place() {
+        let mut datetime = DateTimeDefaultUnix::<Utc>::default();
+
+        *datetime += chrono::Duration::hours(1);
+        *datetime.as_mut() += chrono::Duration::minutes(1);
+        datetime.set(*datetime + chrono::Duration::seconds(1));
+
+        assert_eq!(datetime.as_ref().to_rfc3339(), "1970-01-01T01:01:01+00:00");
+    }
+
+    #[test]
+    fn set_keeps_offset() {
+        let mut datetime = DateTimeDefaultUnix::<FixedOffset, 9>::default();
+
+        datetime.set(DateTime::parse_from_rfc3339("2022-01-01T00:00:00-05:00").unwrap());
+        assert_eq!(datetime.to_rfc3339(), "2022-01-01T14:00:00+09:00");
+
+        datetime.set(Utc.timestamp_opt(1_640_995_200, 0).unwrap());
+        assert_eq!(datetime.to_rfc3339(), "2022-01-01T09:00:00+09:00");
+        assert_eq!(datetime.offset().local_minus_utc(), 9 * 3600);
+    }
+
+    #[test]
+    fn from_foreign_offset() {
+        let datetime = DateTime::parse_from_rfc3339("2022-01-01T00:00:00+00:00").unwrap();
+        let tokyo = DateTimeDefaultNow::<FixedOffset, 9>::from(datetime);
+
+        assert_eq!(tokyo.to_rfc3339(), "2022-01-01T09:00:00+09:00");
+        assert_eq!(tokyo, datetime);
+
+        let kolkata: DateTimeDefaultUnix<FixedOffset, 5, 30> = datetime.into();
+
+        assert_eq!(kolkata.to_string(), "2022-01-01 05:30:00 +05:30");
+    }
+
+    #[test]
+    fn borrow_as_key() {
+        let mut counts = std::collections::HashMap::new();
+        counts.insert(DateTimeDefaultUnix::<Utc>::default(), 1);
+
+        assert_eq!(counts.get(&DateTime::<Utc>::default()), Some(&1));
+    }
+
+    #[test]
+    fn now_and_unix() {
+        let now = DateTimeDefaultNow::<FixedOffset, 9>::default();
+        let unix = DateTimeDefaultUnix::<FixedOffset, 9>::from(now);
+
+        assert_eq!(unix, *now);
+        assert_eq!(DateTimeDefaultNow::from(unix), now);
+    }
+
+    #[test]
+    fn compare_across_zones() {
+        let tokyo = DateTimeDefaultNow::<FixedOffset, 9>::default();
+        let utc = DateTimeDefaultNow::<Utc>::default();
+        let unix = DateTimeDefaultUnix::<FixedOffset, -5, -30>::default();
+
+        assert_eq!(tokyo, utc);
+        assert!(unix < tokyo);
+        assert!(utc > unix);
+        assert!(tokyo == *utc);
+        assert!(utc.with_timezone(&Local) == tokyo);
+        assert!(*unix < utc);
+    }
+
+    #[test]
+    fn convert_offset() {
+        let utc = DateTimeDefaultUnix::<Utc>::default();
+        let tokyo: DateTimeDefaultUnix<FixedOffset, 9> = utc.with_offset::<9>();
+        let back: DateTimeDefaultUnix<Utc> = tokyo.to_utc();
+
+        assert_eq!(tokyo.to_rfc3339(), "1970-01-01T09:00:00+09:00");
+        assert_eq!(
+            tokyo.with_offset_hm::<-5, -30>().to_rfc3339(),
+            "1969-12-31T18:30:00-05:30"
+        );
+        assert_eq!(back.to_rfc3339(), "1970-01-01T00:00:00+00:00");
+        assert_eq!(back, tokyo);
+    }
+}
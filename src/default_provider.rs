@@ -0,0 +1,198 @@
+use chrono::{
+    DateTime, Datelike, Duration, LocalResult, NaiveDate, Offset, SubsecRound, TimeZone, Utc,
+};
+
+use crate::{
+    clock,
+    const_assert::{AssertEpochSecs, AssertFirstWeekday, AssertFromNowSecs, AssertSubsecDigits},
+};
+
+/// # Provider of the default value of `DateTimeWithDefault`.
+///
+/// The default is given in the time zone of the NewType,
+/// so it may depend on the zone, e.g. the start of the local day.
+pub trait DefaultProvider {
+    fn default_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz>;
+}
+
+/// The current time of [`clock::now`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Now;
+
+impl DefaultProvider for Now {
+    fn default_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz> {
+        clock::now().with_timezone(tz)
+    }
+}
+
+/// The current time of [`clock::now`], truncated to `SUBSEC_DIGITS` fractional digits.
+///
+/// 0, 3 and 6 digits match the precision of seconds, milliseconds and microseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct NowTruncated<const SUBSEC_DIGITS: u16>;
+
+impl<const SUBSEC_DIGITS: u16> DefaultProvider for NowTruncated<SUBSEC_DIGITS> {
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn default_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz> {
+        AssertSubsecDigits::<SUBSEC_DIGITS>::OK;
+
+        clock::now().trunc_subsecs(SUBSEC_DIGITS).with_timezone(tz)
+    }
+}
+
+/// `OFFSET_SECS` seconds from the current time of [`clock::now`].
+///
+/// Negative values are in the past.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct FromNow<const OFFSET_SECS: i64>;
+
+impl<const OFFSET_SECS: i64> DefaultProvider for FromNow<OFFSET_SECS> {
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn default_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz> {
+        AssertFromNowSecs::<OFFSET_SECS>::OK;
+
+        clock::now()
+            .checked_add_signed(Duration::seconds(OFFSET_SECS))
+            .expect("DateTime out of range")
+            .with_timezone(tz)
+    }
+}
+
+/// The UNIX epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Unix;
+
+impl DefaultProvider for Unix {
+    fn default_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz> {
+        DateTime::<Utc>::default().with_timezone(tz)
+    }
+}
+
+/// `EPOCH_SECS` non-leap seconds since the UNIX epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct At<const EPOCH_SECS: i64>;
+
+impl<const EPOCH_SECS: i64> DefaultProvider for At<EPOCH_SECS> {
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn default_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz> {
+        AssertEpochSecs::<EPOCH_SECS>::OK;
+
+        Utc.timestamp_opt(EPOCH_SECS, 0).unwrap().with_timezone(tz)
+    }
+}
+
+/// The latest instant of chrono whose local time is representable in the time zone.
+///
+/// It is `DateTime::<Utc>::MAX_UTC` moved back by positive offsets,
+/// so that the local time can be displayed and serialized without overflowing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Max;
+
+impl DefaultProvider for Max {
+    fn default_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz> {
+        let max = DateTime::<Utc>::MAX_UTC.naive_utc();
+        let offset = tz.offset_from_utc_datetime(&max).fix().local_minus_utc();
+
+        tz.from_utc_datetime(&(max - Duration::seconds(offset.max(0).into())))
+    }
+}
+
+/// The earliest instant of chrono whose local time is representable in the time zone.
+///
+/// It is `DateTime::<Utc>::MIN_UTC` moved forward by negative offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Min;
+
+impl DefaultProvider for Min {
+    fn default_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz> {
+        let min = DateTime::<Utc>::MIN_UTC.naive_utc();
+        let offset = tz.offset_from_utc_datetime(&min).fix().local_minus_utc();
+
+        tz.from_utc_datetime(&(min - Duration::seconds(offset.min(0).into())))
+    }
+}
+
+/// The start of the current day in the time zone of the NewType.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct StartOfDay;
+
+impl DefaultProvider for StartOfDay {
+    fn default_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz> {
+        start_of(tz, today(tz))
+    }
+}
+
+/// The start of the current week in the time zone of the NewType.
+///
+/// `FIRST_WEEKDAY` is the number of days from Monday of the first day of the week,
+/// i.e. 0 for the ISO week starting on Monday and 6 for weeks starting on Sunday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct StartOfWeek<const FIRST_WEEKDAY: u8 = 0>;
+
+impl<const FIRST_WEEKDAY: u8> DefaultProvider for StartOfWeek<FIRST_WEEKDAY> {
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn default_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz> {
+        AssertFirstWeekday::<FIRST_WEEKDAY>::OK;
+
+        let today = today(tz);
+        let days = (today.weekday().num_days_from_monday() + 7 - u32::from(FIRST_WEEKDAY)) % 7;
+
+        start_of(tz, today - Duration::days(days.into()))
+    }
+}
+
+/// The start of the current month in the time zone of the NewType.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct StartOfMonth;
+
+impl DefaultProvider for StartOfMonth {
+    fn default_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz> {
+        start_of(tz, today(tz).with_day(1).unwrap())
+    }
+}
+
+/// The start of the current year in the time zone of the NewType.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct StartOfYear;
+
+impl DefaultProvider for StartOfYear {
+    fn default_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz> {
+        start_of(tz, today(tz).with_ordinal(1).unwrap())
+    }
+}
+
+fn today<Tz: TimeZone>(tz: &Tz) -> NaiveDate {
+    clock::now().with_timezone(tz).naive_local().date()
+}
+
+/// The first instant of `date` in `tz`.
+///
+/// When midnight is repeated by a DST change, the earlier one is taken.
+/// When midnight is skipped, the day starts at the end of the gap.
+pub fn start_of<Tz: TimeZone>(tz: &Tz, date: NaiveDate) -> DateTime<Tz> {
+    let midnight = date.and_hms_opt(0, 0, 0).unwrap();
+
+    match tz.from_local_datetime(&midnight) {
+        LocalResult::Single(datetime) | LocalResult::Ambiguous(datetime, _) => datetime,
+        LocalResult::None => {
+            // The local date only moves forward around a gap,
+            // so search the first second of `date` within a day of midnight in UTC.
+            let mut low = (midnight - Duration::days(1)).timestamp();
+            let mut high = (midnight + Duration::days(1)).timestamp();
+            while low + 1 < high {
+                let mid = low + (high - low) / 2;
+                if tz.timestamp_opt(mid, 0).unwrap().naive_local().date() < date {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+
+            tz.timestamp_opt(high, 0).unwrap()
+        }
+    }
+}
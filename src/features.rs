@@ -1,2 +1,20 @@
+#[cfg(feature = "chrono-tz")]
+pub mod chrono_tz;
+
+#[cfg(feature = "clap")]
+mod clap;
+
+#[cfg(feature = "diesel")]
+mod diesel;
+
+#[cfg(feature = "rusqlite")]
+pub mod rusqlite;
+
 #[cfg(feature = "schemars")]
-mod schemars;
+pub mod schemars;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "sqlx")]
+mod sqlx;
@@ -0,0 +1,523 @@
+//! # DateTime with named IANA time zone.
+//!
+//! A named time zone is chosen at the type level by implementing [`NamedTimeZone`] for a marker type,
+//! and used as `Tz` of the NewTypes through [`NamedTz`].
+//! DST rules are applied by `chrono-tz`.
+//!
+//! ```
+//! use chrono::{DateTime, Utc};
+//! use datetime_default::{clock, DateTimeDefaultNow, NamedTimeZone, NamedTz};
+//!
+//! #[derive(Debug, Clone, Copy)]
+//! struct NewYork;
+//!
+//! impl NamedTimeZone for NewYork {
+//!     const TZ: chrono_tz::Tz = chrono_tz::America::New_York;
+//! }
+//!
+//! let winter = DateTime::parse_from_rfc3339("2022-01-10T12:00:00Z").unwrap();
+//! let summer = DateTime::parse_from_rfc3339("2022-07-10T12:00:00Z").unwrap();
+//!
+//! clock::with_frozen_now(winter, || {
+//!     let datetime = DateTimeDefaultNow::<NamedTz<NewYork>>::default();
+//!
+//!     assert_eq!(datetime.to_rfc3339(), "2022-01-10T07:00:00-05:00");
+//!     assert_eq!(datetime.to_string(), "2022-01-10 07:00:00 -05:00 America/New_York");
+//! });
+//!
+//! clock::with_frozen_now(summer, || {
+//!     let datetime = DateTimeDefaultNow::<NamedTz<NewYork>>::default();
+//!
+//!     assert_eq!(datetime.to_rfc3339(), "2022-07-10T08:00:00-04:00");
+//! });
+//! ```
+//!
+use std::{fmt, hash::Hash, marker::PhantomData, str::FromStr};
+
+use chrono::{DateTime, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc};
+
+use crate::{
+    const_assert::AssertZeroOffset, DateTimeWithDefault, DefaultProvider, FromUtc, NaivePart,
+    NaiveWithDefault,
+};
+
+/// Marker of a named IANA time zone.
+pub trait NamedTimeZone: fmt::Debug + Copy + 'static {
+    const TZ: chrono_tz::Tz;
+}
+
+/// `TimeZone` of the named IANA time zone `Z`.
+pub struct NamedTz<Z>(PhantomData<Z>);
+
+/// Offset of [`NamedTz`], displayed as the offset followed by the IANA time zone name.
+///
+/// The offset tells the two instants of a repeated local time apart, e.g. at the end of DST.
+pub struct NamedTzOffset<Z> {
+    offset: FixedOffset,
+    _zone: PhantomData<Z>,
+}
+
+/// Error of parsing a NewType with [`NamedTz`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseNamedTzError {
+    /// The input is not an RFC 3339 datetime nor a datetime followed by a time zone name.
+    Invalid(chrono::ParseError),
+    /// The time zone name does not match the time zone of the type.
+    TimeZone(String),
+    /// The local datetime does not exist or is ambiguous in the time zone,
+    /// or its offset is not the one of the time zone.
+    LocalDateTime(NaiveDateTime),
+}
+
+impl<Z: NamedTimeZone> NamedTz<Z> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// The IANA time zone name.
+    pub fn name(&self) -> &'static str {
+        Z::TZ.name()
+    }
+}
+
+impl<Z> Clone for NamedTz<Z> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Z> Copy for NamedTz<Z> {}
+
+impl<Z: NamedTimeZone> Default for NamedTz<Z> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Z: NamedTimeZone> fmt::Debug for NamedTz<Z> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl<Z> PartialEq for NamedTz<Z> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<Z> Eq for NamedTz<Z> {}
+
+impl<Z> PartialOrd for NamedTz<Z> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Z> Ord for NamedTz<Z> {
+    fn cmp(&self, _: &Self) -> std::cmp::Ordering {
+        std::cmp::Ordering::Equal
+    }
+}
+
+impl<Z> Hash for NamedTz<Z> {
+    fn hash<H: std::hash::Hasher>(&self, _: &mut H) {}
+}
+
+impl<Z: NamedTimeZone> NamedTzOffset<Z> {
+    fn new<O: Offset>(offset: O) -> Self {
+        Self {
+            offset: offset.fix(),
+            _zone: PhantomData,
+        }
+    }
+}
+
+impl<Z> Clone for NamedTzOffset<Z> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Z> Copy for NamedTzOffset<Z> {}
+
+impl<Z: NamedTimeZone> Offset for NamedTzOffset<Z> {
+    fn fix(&self) -> FixedOffset {
+        self.offset
+    }
+}
+
+impl<Z: NamedTimeZone> fmt::Debug for NamedTzOffset<Z> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Keep the `Debug` of `DateTime` in RFC 3339, as serde relies on it.
+        fmt::Debug::fmt(&self.offset, f)
+    }
+}
+
+impl<Z: NamedTimeZone> fmt::Display for NamedTzOffset<Z> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.offset, Z::TZ.name())
+    }
+}
+
+impl<Z: NamedTimeZone> TimeZone for NamedTz<Z> {
+    type Offset = NamedTzOffset<Z>;
+
+    fn from_offset(_: &Self::Offset) -> Self {
+        Self::new()
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<Self::Offset> {
+        Z::TZ.offset_from_local_date(local).map(NamedTzOffset::new)
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<Self::Offset> {
+        Z::TZ
+            .offset_from_local_datetime(local)
+            .map(NamedTzOffset::new)
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> Self::Offset {
+        NamedTzOffset::new(Z::TZ.offset_from_utc_date(utc))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> Self::Offset {
+        NamedTzOffset::new(Z::TZ.offset_from_utc_datetime(utc))
+    }
+}
+
+impl fmt::Display for ParseNamedTzError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Invalid(err) => err.fmt(f),
+            Self::TimeZone(name) => write!(f, "unexpected time zone: {name}"),
+            Self::LocalDateTime(local) => write!(f, "no single local datetime: {local}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseNamedTzError {}
+
+/// Parses RFC 3339, or the `Display` format such as `2022-10-11 08:40:11 +09:00 Asia/Tokyo`.
+///
+/// The offset may be omitted when the local datetime is not ambiguous.
+fn parse_named<Z: NamedTimeZone>(s: &str) -> Result<DateTime<NamedTz<Z>>, ParseNamedTzError> {
+    let rfc3339_err = match DateTime::<FixedOffset>::from_str(s) {
+        Ok(datetime) => return Ok(datetime.with_timezone(&NamedTz::new())),
+        Err(err) => err,
+    };
+
+    let (local, name) = match s.trim().rsplit_once(' ') {
+        Some(split) => split,
+        None => return Err(ParseNamedTzError::Invalid(rfc3339_err)),
+    };
+    let with_offset = DateTime::<FixedOffset>::from_str(local).ok();
+    let local = match with_offset {
+        Some(datetime) => datetime.naive_local(),
+        None => NaiveDateTime::parse_from_str(local, "%Y-%m-%d %H:%M:%S%.f")
+            .map_err(ParseNamedTzError::Invalid)?,
+    };
+
+    if name != Z::TZ.name() {
+        return Err(ParseNamedTzError::TimeZone(name.to_owned()));
+    }
+
+    match with_offset {
+        Some(datetime) => {
+            let named = datetime.with_timezone(&NamedTz::<Z>::new());
+            (named.offset().fix() == *datetime.offset())
+                .then_some(named)
+                .ok_or(ParseNamedTzError::LocalDateTime(local))
+        }
+        None => NamedTz::<Z>::new()
+            .from_local_datetime(&local)
+            .single()
+            .ok_or(ParseNamedTzError::LocalDateTime(local)),
+    }
+}
+
+/// Parses the `Display` format of any named zone, e.g. `2022-10-11 08:40:11 +09:00 Asia/Tokyo`.
+///
+/// Used by `parse_lenient`, which converts the instant into its own time zone.
+pub(crate) fn parse_display(s: &str) -> Option<DateTime<FixedOffset>> {
+    let (local, name) = s.trim().rsplit_once(' ')?;
+    let tz = chrono_tz::Tz::from_str(name).ok()?;
+    let datetime = DateTime::<FixedOffset>::from_str(local).ok()?;
+
+    (datetime.with_timezone(&tz).offset().fix() == *datetime.offset()).then_some(datetime)
+}
+
+impl<Z: NamedTimeZone, P: DefaultProvider> Default for DateTimeWithDefault<NamedTz<Z>, P, 0, 0> {
+    fn default() -> Self {
+        DateTimeWithDefault::from_inner(P::default_in(&NamedTz::new()))
+    }
+}
+
+impl<Z: NamedTimeZone, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromUtc
+    for DateTimeWithDefault<NamedTz<Z>, P, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn from_utc(datetime: &DateTime<Utc>) -> Self {
+        AssertZeroOffset::<OFFSET_HOURS, OFFSET_MINUTES>::OK;
+
+        DateTimeWithDefault::from_inner(datetime.with_timezone(&NamedTz::new()))
+    }
+}
+
+impl<Z: NamedTimeZone, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromStr
+    for DateTimeWithDefault<NamedTz<Z>, P, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    type Err = ParseNamedTzError;
+
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AssertZeroOffset::<OFFSET_HOURS, OFFSET_MINUTES>::OK;
+
+        parse_named(s).map(DateTimeWithDefault::from_inner)
+    }
+}
+
+impl<T: NaivePart, Z: NamedTimeZone, P: DefaultProvider> Default
+    for NaiveWithDefault<T, NamedTz<Z>, P, 0, 0>
+{
+    fn default() -> Self {
+        NaiveWithDefault::from(T::from_local(&P::default_in(&NamedTz::<Z>::new())))
+    }
+}
+
+impl<T: NaivePart, Z: NamedTimeZone, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromUtc
+    for NaiveWithDefault<T, NamedTz<Z>, P, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn from_utc(datetime: &DateTime<Utc>) -> Self {
+        AssertZeroOffset::<OFFSET_HOURS, OFFSET_MINUTES>::OK;
+
+        NaiveWithDefault::from(T::from_local(&datetime.with_timezone(&NamedTz::<Z>::new())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, Utc};
+
+    use super::{NamedTimeZone, NamedTz, ParseNamedTzError};
+    use crate::{
+        clock, DateTimeDefaultAt, DateTimeDefaultNow, DateTimeDefaultStartOfDay,
+        DateTimeDefaultStartOfWeek, DateTimeDefaultUnix, FromUtc, NaiveDateDefaultToday,
+    };
+
+    #[derive(Debug, Clone, Copy)]
+    struct Tokyo;
+
+    impl NamedTimeZone for Tokyo {
+        const TZ: chrono_tz::Tz = chrono_tz::Asia::Tokyo;
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct NewYork;
+
+    impl NamedTimeZone for NewYork {
+        const TZ: chrono_tz::Tz = chrono_tz::America::New_York;
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct SaoPaulo;
+
+    impl NamedTimeZone for SaoPaulo {
+        const TZ: chrono_tz::Tz = chrono_tz::America::Sao_Paulo;
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct Havana;
+
+    impl NamedTimeZone for Havana {
+        const TZ: chrono_tz::Tz = chrono_tz::America::Havana;
+    }
+
+    #[derive(Default)]
+    struct Test {
+        created_at: DateTimeDefaultUnix<NamedTz<Tokyo>>,
+        updated_at: DateTimeDefaultNow<NamedTz<Tokyo>>,
+    }
+
+    #[test]
+    fn default_derive_struct() {
+        let test = Test::default();
+
+        assert_eq!(test.created_at.to_rfc3339(), "1970-01-01T09:00:00+09:00");
+        assert_eq!(
+            test.updated_at.to_rfc3339(),
+            "2022-10-11T08:40:11.695164300+09:00"
+        );
+    }
+
+    #[test]
+    fn default_at() {
+        assert_eq!(
+            DateTimeDefaultAt::<NamedTz<NewYork>, 946684800>::default().to_rfc3339(),
+            "1999-12-31T19:00:00-05:00"
+        );
+    }
+
+    #[test]
+    fn dst() {
+        let summer = DateTime::parse_from_rfc3339("2022-07-10T12:00:00Z").unwrap();
+
+        clock::with_frozen_now(summer, || {
+            assert_eq!(
+                DateTimeDefaultNow::<NamedTz<NewYork>>::default().to_rfc3339(),
+                "2022-07-10T08:00:00-04:00"
+            );
+        });
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            DateTimeDefaultUnix::<NamedTz<Tokyo>>::default().to_string(),
+            "1970-01-01 09:00:00 +09:00 Asia/Tokyo"
+        );
+    }
+
+    #[test]
+    fn from_str_round_trip() {
+        let datetime = DateTimeDefaultNow::<NamedTz<NewYork>>::default();
+
+        assert_eq!(
+            datetime
+                .to_string()
+                .parse::<DateTimeDefaultNow<NamedTz<NewYork>>>()
+                .unwrap(),
+            datetime
+        );
+    }
+
+    #[test]
+    fn from_str_rfc3339() {
+        let datetime: DateTimeDefaultUnix<NamedTz<Tokyo>> = "2022-10-10T23:40:11Z".parse().unwrap();
+
+        assert_eq!(datetime.to_rfc3339(), "2022-10-11T08:40:11+09:00");
+    }
+
+    #[test]
+    fn from_str_other_time_zone() {
+        assert_eq!(
+            "2022-10-11 08:40:11 Asia/Tokyo".parse::<DateTimeDefaultNow<NamedTz<NewYork>>>(),
+            Err(ParseNamedTzError::TimeZone("Asia/Tokyo".to_owned()))
+        );
+    }
+
+    #[test]
+    fn from_str_fall_back_round_trip() {
+        // 01:30 is repeated at the end of DST, first in -04:00 and then in -05:00.
+        for utc in ["2022-11-06T05:30:00Z", "2022-11-06T06:30:00Z"] {
+            let datetime: DateTimeDefaultNow<NamedTz<NewYork>> = utc.parse().unwrap();
+
+            assert_eq!(datetime.naive_local().to_string(), "2022-11-06 01:30:00");
+            assert_eq!(
+                datetime
+                    .to_string()
+                    .parse::<DateTimeDefaultNow<NamedTz<NewYork>>>()
+                    .unwrap(),
+                datetime
+            );
+        }
+    }
+
+    #[test]
+    fn from_str_wrong_offset() {
+        assert!(matches!(
+            "2022-10-11 08:40:11 +08:00 Asia/Tokyo".parse::<DateTimeDefaultNow<NamedTz<Tokyo>>>(),
+            Err(ParseNamedTzError::LocalDateTime(_))
+        ));
+        assert_eq!(
+            "2022-10-11 08:40:11 Asia/Tokyo"
+                .parse::<DateTimeDefaultNow<NamedTz<Tokyo>>>()
+                .unwrap()
+                .to_rfc3339(),
+            "2022-10-11T08:40:11+09:00"
+        );
+    }
+
+    #[test]
+    fn from_str_ambiguous() {
+        assert!(matches!(
+            "2022-11-06 01:30:00 America/New_York".parse::<DateTimeDefaultNow<NamedTz<NewYork>>>(),
+            Err(ParseNamedTzError::LocalDateTime(_))
+        ));
+    }
+
+    #[test]
+    fn from_utc() {
+        let datetime = DateTimeDefaultUnix::<NamedTz<Tokyo>>::from_utc(&Utc::now());
+
+        assert_eq!(datetime.offset().to_string(), "+09:00 Asia/Tokyo");
+    }
+
+    #[test]
+    fn naive_today() {
+        let evening = DateTime::parse_from_rfc3339("2022-07-11T02:00:00Z").unwrap();
+
+        clock::with_frozen_now(evening, || {
+            assert_eq!(
+                NaiveDateDefaultToday::<NamedTz<NewYork>>::default().to_string(),
+                "2022-07-10"
+            );
+        });
+        assert_eq!(
+            NaiveDateDefaultToday::<NamedTz<Tokyo>>::from_utc(&evening.with_timezone(&Utc))
+                .to_string(),
+            "2022-07-11"
+        );
+    }
+
+    #[test]
+    fn start_of_day_skipped_midnight() {
+        // DST started at midnight, and the day began at 01:00.
+        let now = DateTime::parse_from_rfc3339("2018-11-04T12:00:00Z").unwrap();
+
+        clock::with_frozen_now(now, || {
+            assert_eq!(
+                DateTimeDefaultStartOfDay::<NamedTz<SaoPaulo>>::default().to_rfc3339(),
+                "2018-11-04T01:00:00-02:00"
+            );
+            assert_eq!(
+                DateTimeDefaultStartOfWeek::<NamedTz<SaoPaulo>, 6>::default().to_rfc3339(),
+                "2018-11-04T01:00:00-02:00"
+            );
+        });
+    }
+
+    #[test]
+    fn start_of_day_repeated_midnight() {
+        // DST ended at 01:00, and midnight was repeated.
+        let now = DateTime::parse_from_rfc3339("2022-11-06T12:00:00Z").unwrap();
+
+        clock::with_frozen_now(now, || {
+            assert_eq!(
+                DateTimeDefaultStartOfDay::<NamedTz<Havana>>::default().to_rfc3339(),
+                "2022-11-06T00:00:00-04:00"
+            );
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Event {
+            starts_at: DateTimeDefaultNow<NamedTz<NewYork>>,
+        }
+
+        let event: Event = serde_json::from_str(r#"{"starts_at":"2022-11-06T06:30:00Z"}"#).unwrap();
+
+        assert_eq!(event.starts_at.to_rfc3339(), "2022-11-06T01:30:00-05:00");
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"starts_at":"2022-11-06T01:30:00-05:00"}"#
+        );
+    }
+}
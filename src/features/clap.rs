@@ -0,0 +1,237 @@
+//! clap support for the newtypes.
+//!
+//! The NewTypes implement `ValueParserFactory`, so they can be used as `#[arg]` fields
+//! without a `value_parser`. Values are read by `parse_lenient`,
+//! which accepts relative times such as `now-1h`, `today`, epoch seconds and ISO 8601.
+//! An invalid value is reported with a tip listing the accepted formats.
+//!
+//! `default_value_t` writes the default with `Display`, which `parse_lenient` reads back.
+//! Prefer `default_value = "now"` for the `Now` provider:
+//! it is evaluated when the arguments are parsed, and `--help` shows `[default: now]`.
+//!
+//! ```
+//! use chrono::Utc;
+//! use clap::Parser;
+//! use datetime_default::{DateTimeDefaultNow, DateTimeDefaultUnix};
+//!
+//! #[derive(Parser)]
+//! struct Args {
+//!     #[arg(long, default_value_t)]
+//!     since: DateTimeDefaultUnix<Utc>,
+//!     #[arg(long, default_value = "now")]
+//!     until: DateTimeDefaultNow<Utc>,
+//! }
+//!
+//! let args = Args::parse_from(["app", "--until", "2024-05-01 12:00"]);
+//!
+//! assert_eq!(args.since.timestamp(), 0);
+//! assert_eq!(args.until.to_rfc3339(), "2024-05-01T12:00:00+00:00");
+//! ```
+//!
+use std::{ffi::OsStr, str::FromStr};
+
+use chrono::TimeZone;
+use clap::{
+    builder::{StyledStr, TypedValueParser, ValueParserFactory},
+    error::{ContextKind, ContextValue},
+    Arg, Command, Error,
+};
+
+use crate::{DateTimeWithDefault, FromUtc, NaivePart, NaiveWithDefault, ParseLenientError};
+
+/// Tip shown under an invalid value.
+const FORMATS_TIP: &str =
+    "use now, now-1h30m, today, yesterday, tomorrow, epoch seconds, 2024-05-01 12:00 or 2024-05-01T12:00:00+09:00";
+
+/// `TypedValueParser` of the NewTypes, by `parse_lenient`.
+pub struct LenientValueParser<T>(fn(&str) -> Result<T, ParseLenientError>);
+
+impl<T> Clone for LenientValueParser<T> {
+    fn clone(&self) -> Self {
+        Self(self.0)
+    }
+}
+
+impl<T> TypedValueParser for LenientValueParser<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    type Value = T;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<T, Error> {
+        self.0.parse_ref(cmd, arg, value).map_err(|mut err| {
+            err.insert(
+                ContextKind::Suggested,
+                ContextValue::StyledStrs(vec![StyledStr::from(FORMATS_TIP)]),
+            );
+            err
+        })
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> ValueParserFactory
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Self: FromUtc + Clone + Send + Sync + 'static,
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Parser = LenientValueParser<Self>;
+
+    fn value_parser() -> Self::Parser {
+        LenientValueParser(Self::parse_lenient)
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> ValueParserFactory
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Self: Clone + Send + Sync + 'static,
+    T: NaivePart + FromStr,
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>: FromUtc,
+{
+    type Parser = LenientValueParser<Self>;
+
+    fn value_parser() -> Self::Parser {
+        LenientValueParser(Self::parse_lenient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::{FixedOffset, Local, Utc};
+    use clap::{error::ErrorKind, CommandFactory, Parser};
+
+    use crate::{
+        DateTimeDefaultNow, DateTimeDefaultUnix, NaiveDateDefaultToday, NaiveDateTimeDefaultNow,
+        NaiveTimeDefault,
+    };
+
+    #[derive(Parser)]
+    struct Args {
+        #[arg(long, default_value = "now")]
+        since: DateTimeDefaultNow<Utc>,
+        #[arg(long, default_value_t)]
+        until: DateTimeDefaultNow<FixedOffset, 9>,
+        #[arg(long, default_value_t)]
+        epoch: DateTimeDefaultUnix<Utc>,
+        #[arg(long, default_value_t)]
+        day: NaiveDateDefaultToday<Utc>,
+    }
+
+    #[test]
+    fn defaults() {
+        let args = Args::parse_from(["app"]);
+
+        assert_eq!(
+            args.since.to_rfc3339(),
+            "2022-10-10T23:40:11.695164300+00:00"
+        );
+        assert_eq!(
+            args.until.to_rfc3339(),
+            "2022-10-11T08:40:11.695164300+09:00"
+        );
+        assert_eq!(args.epoch.to_rfc3339(), "1970-01-01T00:00:00+00:00");
+        assert_eq!(args.day.to_string(), "2022-10-10");
+    }
+
+    #[test]
+    fn lenient() {
+        let args = Args::parse_from([
+            "app",
+            "--since",
+            "now-1d",
+            "--until",
+            "2024-05-01 12:00",
+            "--epoch",
+            "1700000000",
+            "--day",
+            "yesterday",
+        ]);
+
+        assert_eq!(
+            args.since.to_rfc3339(),
+            "2022-10-09T23:40:11.695164300+00:00"
+        );
+        assert_eq!(args.until.to_rfc3339(), "2024-05-01T12:00:00+09:00");
+        assert_eq!(args.epoch.to_rfc3339(), "2023-11-14T22:13:20+00:00");
+        assert_eq!(args.day.to_string(), "2022-10-09");
+    }
+
+    #[test]
+    fn display_round_trip() {
+        #[derive(Parser)]
+        struct Args {
+            #[arg(long, default_value_t)]
+            local: DateTimeDefaultNow<Local>,
+            #[arg(long, default_value_t)]
+            naive: NaiveDateTimeDefaultNow<FixedOffset, -5, -30>,
+            #[arg(long, default_value_t)]
+            time: NaiveTimeDefault,
+        }
+
+        let args = Args::parse_from(["app"]);
+
+        assert_eq!(args.local, DateTimeDefaultNow::<Local>::default());
+        assert_eq!(args.naive.to_string(), "2022-10-10 18:10:11.695164300");
+        assert_eq!(args.time.to_string(), "00:00:00");
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn named_tz_display_round_trip() {
+        use crate::{NamedTimeZone, NamedTz};
+
+        #[derive(Debug, Clone, Copy)]
+        struct Tokyo;
+
+        impl NamedTimeZone for Tokyo {
+            const TZ: chrono_tz::Tz = chrono_tz::Asia::Tokyo;
+        }
+
+        #[derive(Parser)]
+        struct Args {
+            #[arg(long, default_value_t)]
+            since: DateTimeDefaultUnix<NamedTz<Tokyo>>,
+            #[arg(long, default_value_t)]
+            until: DateTimeDefaultNow<NamedTz<Tokyo>>,
+        }
+
+        let args = Args::parse_from(["app"]);
+
+        assert_eq!(args.since.to_rfc3339(), "1970-01-01T09:00:00+09:00");
+        assert_eq!(
+            args.until.to_rfc3339(),
+            "2022-10-11T08:40:11.695164300+09:00"
+        );
+
+        let args = Args::parse_from(["app", "--since", "2022-10-11 08:40:11 +09:00 Asia/Tokyo"]);
+
+        assert_eq!(args.since.to_rfc3339(), "2022-10-11T08:40:11+09:00");
+    }
+
+    #[test]
+    fn invalid_value() {
+        let err = match Args::try_parse_from(["app", "--since", "now+1y"]) {
+            Ok(_) => panic!("now+1y is invalid"),
+            Err(err) => err,
+        };
+
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+        let rendered = err.to_string();
+        assert!(rendered.contains(
+            "invalid value 'now+1y' for '--since <SINCE>': invalid relative time such as now-1h"
+        ));
+        assert!(rendered.contains("tip: use now, now-1h30m, today"));
+    }
+
+    #[test]
+    fn help() {
+        let help = Args::command().render_help().to_string();
+
+        assert!(help.contains("--since <SINCE>  [default: now]"));
+    }
+}
@@ -0,0 +1,414 @@
+//! Diesel support for the `DateTime` newtypes.
+//!
+//! The NewTypes can be used as `Timestamptz` and `Timestamp` on Postgres,
+//! and as `TimestamptzSqlite` and `Timestamp` on SQLite (`diesel-sqlite` feature).
+//! `Timestamp` columns store the UTC time without an offset.
+//! Loaded values are converted into the time zone of the NewType.
+//! `NaiveWithDefault` can be used wherever its inner naive value can, e.g. as `Date`.
+//!
+use std::{fmt::Debug, marker::PhantomData};
+
+use chrono::{DateTime, TimeZone, Utc};
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql, Queryable},
+    expression::{
+        is_aggregate, AppearsOnTable, AsExpression, Expression, SelectableExpression,
+        TypedExpressionType, ValidGrouping,
+    },
+    pg::Pg,
+    query_builder::{AstPass, QueryFragment, QueryId},
+    result::QueryResult,
+    serialize::{self, Output, ToSql},
+    sql_types::{
+        is_nullable::NotNull, HasSqlType, Nullable, SingleValue, SqlType, Timestamp, Timestamptz,
+    },
+};
+
+#[cfg(feature = "diesel-sqlite")]
+use diesel::{sql_types::TimestamptzSqlite, sqlite::Sqlite};
+
+use crate::{DateTimeWithDefault, FromUtc, NaiveWithDefault};
+
+/// SQL types the NewTypes can be bound as.
+pub trait SqlTimestamp: SqlType + TypedExpressionType {}
+
+impl SqlTimestamp for Timestamp {}
+
+impl SqlTimestamp for Timestamptz {}
+
+#[cfg(feature = "diesel-sqlite")]
+impl SqlTimestamp for TimestamptzSqlite {}
+
+impl<ST> SqlTimestamp for Nullable<ST> where
+    ST: SqlTimestamp + SqlType<IsNull = NotNull> + SingleValue
+{
+}
+
+/// Backends that can store the NewTypes as `ST`.
+pub trait TimestampBackend<ST>: Backend {
+    fn to_sql<'b, Tz>(
+        datetime: &'b DateTime<Tz>,
+        out: &mut Output<'b, '_, Self>,
+    ) -> serialize::Result
+    where
+        Tz: TimeZone;
+
+    fn from_sql(bytes: Self::RawValue<'_>) -> deserialize::Result<DateTime<Utc>>;
+}
+
+impl TimestampBackend<Timestamptz> for Pg {
+    fn to_sql<'b, Tz>(
+        datetime: &'b DateTime<Tz>,
+        out: &mut Output<'b, '_, Self>,
+    ) -> serialize::Result
+    where
+        Tz: TimeZone,
+    {
+        ToSql::<Timestamptz, Self>::to_sql(datetime, out)
+    }
+
+    fn from_sql(bytes: Self::RawValue<'_>) -> deserialize::Result<DateTime<Utc>> {
+        FromSql::<Timestamptz, Self>::from_sql(bytes)
+    }
+}
+
+impl TimestampBackend<Timestamp> for Pg {
+    fn to_sql<'b, Tz>(
+        datetime: &'b DateTime<Tz>,
+        out: &mut Output<'b, '_, Self>,
+    ) -> serialize::Result
+    where
+        Tz: TimeZone,
+    {
+        ToSql::<Timestamp, Self>::to_sql(&datetime.naive_utc(), &mut out.reborrow())
+    }
+
+    fn from_sql(bytes: Self::RawValue<'_>) -> deserialize::Result<DateTime<Utc>> {
+        FromSql::<Timestamp, Self>::from_sql(bytes).map(|naive| Utc.from_utc_datetime(&naive))
+    }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+impl TimestampBackend<TimestamptzSqlite> for Sqlite {
+    fn to_sql<'b, Tz>(
+        datetime: &'b DateTime<Tz>,
+        out: &mut Output<'b, '_, Self>,
+    ) -> serialize::Result
+    where
+        Tz: TimeZone,
+    {
+        ToSql::<TimestamptzSqlite, Self>::to_sql(datetime, out)
+    }
+
+    fn from_sql(bytes: Self::RawValue<'_>) -> deserialize::Result<DateTime<Utc>> {
+        FromSql::<TimestamptzSqlite, Self>::from_sql(bytes)
+    }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+impl TimestampBackend<Timestamp> for Sqlite {
+    fn to_sql<'b, Tz>(
+        datetime: &'b DateTime<Tz>,
+        out: &mut Output<'b, '_, Self>,
+    ) -> serialize::Result
+    where
+        Tz: TimeZone,
+    {
+        // Same format as `NaiveDateTime`, which borrows from the value and cannot be used here.
+        out.set_value(datetime.naive_utc().format("%F %T%.f").to_string());
+        Ok(serialize::IsNull::No)
+    }
+
+    fn from_sql(bytes: Self::RawValue<'_>) -> deserialize::Result<DateTime<Utc>> {
+        FromSql::<Timestamp, Self>::from_sql(bytes).map(|naive| Utc.from_utc_datetime(&naive))
+    }
+}
+
+/// Bind parameter of a NewType, as `ST`.
+///
+/// Same as the `Bound` of `#[derive(AsExpression)]`, which is not public API of diesel.
+#[derive(Debug, Clone, Copy)]
+pub struct Bind<ST, T>(T, PhantomData<ST>);
+
+impl<ST, T> Bind<ST, T> {
+    fn new(value: T) -> Self {
+        Self(value, PhantomData)
+    }
+}
+
+impl<ST, T> Expression for Bind<ST, T>
+where
+    ST: SqlType + TypedExpressionType,
+{
+    type SqlType = ST;
+}
+
+impl<ST, T, DB> QueryFragment<DB> for Bind<ST, T>
+where
+    DB: Backend + HasSqlType<ST>,
+    T: ToSql<ST, DB>,
+{
+    fn walk_ast<'b>(&'b self, mut pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        pass.push_bind_param(&self.0)
+    }
+}
+
+impl<ST, T> QueryId for Bind<ST, T>
+where
+    ST: QueryId,
+{
+    type QueryId = Bind<ST::QueryId, ()>;
+
+    const HAS_STATIC_QUERY_ID: bool = ST::HAS_STATIC_QUERY_ID;
+}
+
+impl<ST, T, QS> SelectableExpression<QS> for Bind<ST, T> where Self: AppearsOnTable<QS> {}
+
+impl<ST, T, QS> AppearsOnTable<QS> for Bind<ST, T> where Self: Expression {}
+
+impl<ST, T, GB> ValidGrouping<GB> for Bind<ST, T> {
+    type IsAggregate = is_aggregate::Never;
+}
+
+impl<ST, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> AsExpression<ST>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    ST: SqlTimestamp,
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Expression = Bind<ST, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bind::new(self)
+    }
+}
+
+impl<ST, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> AsExpression<ST>
+    for &DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    ST: SqlTimestamp,
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Expression = Bind<ST, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bind::new(self)
+    }
+}
+
+impl<ST, DB, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> ToSql<ST, DB>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    DB: TimestampBackend<ST>,
+    Tz: TimeZone + Debug,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        DB::to_sql(self, out)
+    }
+}
+
+impl<ST, DB, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromSql<ST, DB>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    DB: TimestampBackend<ST>,
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Self: FromUtc,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        DB::from_sql(bytes).map(|datetime| Self::from_utc(&datetime))
+    }
+}
+
+impl<ST, DB, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Queryable<ST, DB>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    ST: SingleValue,
+    DB: Backend,
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Self: FromSql<ST, DB>,
+{
+    type Row = Self;
+
+    fn build(row: Self::Row) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
+
+impl<ST, T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> AsExpression<ST>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    ST: SqlType + TypedExpressionType,
+    T: AsExpression<ST>,
+    Tz: TimeZone,
+{
+    type Expression = Bind<ST, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bind::new(self)
+    }
+}
+
+impl<'a, ST, T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> AsExpression<ST>
+    for &'a NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    ST: SqlType + TypedExpressionType,
+    &'a T: AsExpression<ST>,
+    Tz: TimeZone,
+{
+    type Expression = Bind<ST, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bind::new(self)
+    }
+}
+
+impl<ST, DB, T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> ToSql<ST, DB>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    DB: Backend,
+    T: ToSql<ST, DB>,
+    Tz: TimeZone,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        T::to_sql(self, out)
+    }
+}
+
+impl<ST, DB, T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromSql<ST, DB>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    DB: Backend,
+    T: FromSql<ST, DB>,
+    Tz: TimeZone,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        T::from_sql(bytes).map(NaiveWithDefault::from)
+    }
+}
+
+impl<ST, DB, T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Queryable<ST, DB>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    ST: SingleValue,
+    DB: Backend,
+    Tz: TimeZone,
+    Self: FromSql<ST, DB>,
+{
+    type Row = Self;
+
+    fn build(row: Self::Row) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{FixedOffset, Local, Utc};
+    use diesel::{
+        deserialize::{FromSql, Queryable},
+        expression::AsExpression,
+        pg::Pg,
+        serialize::ToSql,
+        sql_types::{Date, Nullable, Time, Timestamp, Timestamptz},
+    };
+
+    use crate::{
+        DateTimeDefaultAt, DateTimeDefaultNow, DateTimeDefaultUnix, NaiveDateDefaultToday,
+        NaiveDateTimeDefaultNow, NaiveTimeDefault,
+    };
+
+    fn assert_pg<ST, T>()
+    where
+        ST: diesel::sql_types::SingleValue + diesel::expression::TypedExpressionType,
+        T: AsExpression<ST> + ToSql<ST, Pg> + FromSql<ST, Pg> + Queryable<ST, Pg>,
+    {
+    }
+
+    #[test]
+    fn postgres_test() {
+        assert_pg::<Timestamptz, DateTimeDefaultNow<Utc>>();
+        assert_pg::<Timestamptz, DateTimeDefaultUnix<FixedOffset, 9>>();
+        assert_pg::<Timestamp, DateTimeDefaultAt<Local, 946684800>>();
+        assert_pg::<Timestamp, DateTimeDefaultNow<FixedOffset, 5, 30>>();
+        assert_pg::<Date, NaiveDateDefaultToday<FixedOffset, 9>>();
+        assert_pg::<Timestamp, NaiveDateTimeDefaultNow<Local>>();
+        assert_pg::<Time, NaiveTimeDefault>();
+    }
+
+    #[test]
+    fn nullable_test() {
+        fn assert_expression<ST, T>()
+        where
+            ST: diesel::sql_types::SqlType + diesel::expression::TypedExpressionType,
+            T: AsExpression<ST>,
+        {
+        }
+
+        assert_expression::<Nullable<Timestamptz>, DateTimeDefaultNow<Utc>>();
+        assert_expression::<Nullable<Timestamp>, &DateTimeDefaultUnix<Utc>>();
+        assert_expression::<Nullable<Date>, &NaiveDateDefaultToday<Utc>>();
+    }
+
+    #[cfg(feature = "diesel-sqlite")]
+    mod sqlite {
+        use chrono::{FixedOffset, Utc};
+        use diesel::{prelude::*, sql_query, Connection, SqliteConnection};
+
+        use crate::{DateTimeDefaultNow, DateTimeDefaultUnix, NaiveDateDefaultToday};
+
+        diesel::table! {
+            test (id) {
+                id -> Integer,
+                created_at -> TimestamptzSqlite,
+                published_at -> Timestamp,
+                due_on -> Date,
+            }
+        }
+
+        #[derive(Default, Insertable)]
+        #[diesel(table_name = test)]
+        struct NewTest {
+            id: i32,
+            created_at: DateTimeDefaultNow<Utc>,
+            published_at: DateTimeDefaultUnix<FixedOffset, 9>,
+            due_on: NaiveDateDefaultToday<FixedOffset, 9>,
+        }
+
+        #[test]
+        fn sqlite_test() {
+            let mut conn = SqliteConnection::establish(":memory:").unwrap();
+            sql_query(
+                "CREATE TABLE test (id INTEGER PRIMARY KEY, created_at TEXT NOT NULL, published_at TEXT NOT NULL, due_on TEXT NOT NULL)",
+            )
+            .execute(&mut conn)
+            .unwrap();
+
+            diesel::insert_into(test::table)
+                .values(&NewTest::default())
+                .execute(&mut conn)
+                .unwrap();
+
+            let (created_at, published_at, due_on): (
+                DateTimeDefaultNow<FixedOffset, 9>,
+                DateTimeDefaultUnix<Utc>,
+                NaiveDateDefaultToday<Utc>,
+            ) = test::table
+                .select((test::created_at, test::published_at, test::due_on))
+                .first(&mut conn)
+                .unwrap();
+
+            assert_eq!(
+                created_at.to_rfc3339(),
+                "2022-10-11T08:40:11.695164300+09:00"
+            );
+            assert_eq!(published_at.to_rfc3339(), "1970-01-01T00:00:00+00:00");
+            assert_eq!(due_on.to_string(), "2022-10-11");
+        }
+    }
+}
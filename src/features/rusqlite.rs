@@ -0,0 +1,221 @@
+//! rusqlite support for the `DateTime` newtypes.
+//!
+//! Values are written like the inner `DateTime<Tz>`.
+//! They are read from any of the SQLite date and time representations:
+//! RFC 3339 text, integer UNIX seconds and real julian day numbers.
+//! Read values are converted into the time zone of the NewType.
+//! `NaiveWithDefault` is read and written like its inner naive value.
+//!
+//! `NULL` is an error, as for `DateTime<Tz>`.
+//! Wrap the type in [`OrDefault`] to read `NULL` as `Default::default()`.
+//!
+//! ```
+//! use chrono::Utc;
+//! use datetime_default::{rusqlite::OrDefault, DateTimeDefaultUnix};
+//! use rusqlite::Connection;
+//!
+//! let conn = Connection::open_in_memory().unwrap();
+//! let OrDefault(created_at): OrDefault<DateTimeDefaultUnix<Utc>> = conn
+//!     .query_row("SELECT NULL", [], |row| row.get(0))
+//!     .unwrap();
+//!
+//! assert_eq!(created_at, DateTimeDefaultUnix::<Utc>::default());
+//! ```
+//!
+use chrono::{DateTime, TimeZone, Utc};
+use rusqlite::{
+    types::{FromSql, FromSqlError, FromSqlResult, ToSqlOutput, ValueRef},
+    ToSql,
+};
+
+use crate::{DateTimeWithDefault, FromUtc, NaiveWithDefault};
+
+/// Julian day number of the UNIX epoch.
+const UNIX_EPOCH_JULIAN_DAY: f64 = 2_440_587.5;
+
+/// Reads `NULL` as `Default::default()`, and any other value as `T`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct OrDefault<T>(pub T);
+
+impl<T> FromSql for OrDefault<T>
+where
+    T: FromSql + Default,
+{
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Null => Ok(Self(T::default())),
+            _ => T::column_result(value).map(Self),
+        }
+    }
+}
+
+fn column_result(value: ValueRef<'_>) -> FromSqlResult<DateTime<Utc>> {
+    match value {
+        ValueRef::Text(_) => DateTime::<Utc>::column_result(value),
+        ValueRef::Integer(secs) => Utc
+            .timestamp_opt(secs, 0)
+            .single()
+            .ok_or(FromSqlError::OutOfRange(secs)),
+        ValueRef::Real(julian_day) if julian_day.is_finite() => {
+            // `as` saturates, and saturated values are out of range for chrono.
+            let millis = ((julian_day - UNIX_EPOCH_JULIAN_DAY) * 86_400_000.0).round() as i64;
+            Utc.timestamp_millis_opt(millis)
+                .single()
+                .ok_or(FromSqlError::OutOfRange(millis))
+        }
+        _ => Err(FromSqlError::InvalidType),
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> ToSql
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    DateTime<Tz>: ToSql,
+{
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        DateTime::<Tz>::to_sql(self)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromSql
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Self: FromUtc,
+{
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        column_result(value).map(|datetime| Self::from_utc(&datetime))
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> ToSql
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: ToSql,
+    Tz: TimeZone,
+{
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        T::to_sql(self)
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromSql
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: FromSql,
+    Tz: TimeZone,
+{
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        T::column_result(value).map(NaiveWithDefault::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{FixedOffset, Utc};
+    use rusqlite::Connection;
+
+    use super::OrDefault;
+    use crate::{
+        DateTimeDefaultAt, DateTimeDefaultNow, DateTimeDefaultUnix, NaiveDateDefaultToday,
+        NaiveTimeDefault,
+    };
+
+    fn query<T: rusqlite::types::FromSql>(sql: &str) -> rusqlite::Result<T> {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.query_row(sql, [], |row| row.get(0))
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE test (created_at TEXT NOT NULL, updated_at TEXT NOT NULL)",
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO test VALUES (?1, ?2)",
+            (
+                DateTimeDefaultUnix::<FixedOffset, 9>::default(),
+                DateTimeDefaultNow::<Utc>::default(),
+            ),
+        )
+        .unwrap();
+
+        let (created_at, updated_at): (
+            DateTimeDefaultUnix<Utc>,
+            DateTimeDefaultNow<FixedOffset, 9>,
+        ) = conn
+            .query_row("SELECT created_at, updated_at FROM test", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+
+        assert_eq!(created_at.to_rfc3339(), "1970-01-01T00:00:00+00:00");
+        assert_eq!(
+            updated_at.to_rfc3339(),
+            "2022-10-11T08:40:11.695164300+09:00"
+        );
+    }
+
+    #[test]
+    fn text_test() {
+        let datetime: DateTimeDefaultUnix<FixedOffset, 9> =
+            query("SELECT '2000-01-01T00:00:00Z'").unwrap();
+
+        assert_eq!(datetime.to_rfc3339(), "2000-01-01T09:00:00+09:00");
+    }
+
+    #[test]
+    fn integer_test() {
+        let datetime: DateTimeDefaultAt<Utc, 0> = query("SELECT 946684800").unwrap();
+
+        assert_eq!(datetime.to_rfc3339(), "2000-01-01T00:00:00+00:00");
+    }
+
+    #[test]
+    fn julian_day_test() {
+        let datetime: DateTimeDefaultUnix<Utc> =
+            query("SELECT julianday('2000-01-01 12:34:56.789')").unwrap();
+
+        assert_eq!(datetime.to_rfc3339(), "2000-01-01T12:34:56.789+00:00");
+    }
+
+    #[test]
+    fn null_test() {
+        assert!(query::<DateTimeDefaultUnix<Utc>>("SELECT NULL").is_err());
+
+        let OrDefault(datetime): OrDefault<DateTimeDefaultAt<Utc, 946684800>> =
+            query("SELECT NULL").unwrap();
+        assert_eq!(datetime, DateTimeDefaultAt::<Utc, 946684800>::default());
+
+        let OrDefault(datetime): OrDefault<DateTimeDefaultUnix<Utc>> =
+            query("SELECT 946684800").unwrap();
+        assert_eq!(datetime.to_rfc3339(), "2000-01-01T00:00:00+00:00");
+    }
+
+    #[test]
+    fn naive_test() {
+        let conn = Connection::open_in_memory().unwrap();
+        let (due_on, opens_at): (NaiveDateDefaultToday<FixedOffset, 9>, NaiveTimeDefault) = conn
+            .query_row(
+                "SELECT ?1, ?2",
+                (
+                    NaiveDateDefaultToday::<FixedOffset, 9>::default(),
+                    NaiveTimeDefault::default(),
+                ),
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+
+        assert_eq!(due_on.to_string(), "2022-10-11");
+        assert_eq!(opens_at.to_string(), "00:00:00");
+
+        let OrDefault(due_on): OrDefault<NaiveDateDefaultToday<Utc>> =
+            query("SELECT NULL").unwrap();
+        assert_eq!(due_on.to_string(), "2022-10-10");
+    }
+}
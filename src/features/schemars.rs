@@ -1,29 +1,460 @@
-use chrono::{DateTime, TimeZone};
+//! JsonSchema support for the `DateTime` newtypes.
+//!
+//! `DateTimeDefaultNow` is documented with `"x-default": "now"`,
+//! because its default is only known at runtime.
+//! The same goes for `NaiveWithDefault` with the `Now` provider, e.g. `NaiveDateDefaultToday`.
+//! [`PlainSchema`] opts a field back into the plain schema of the inner value.
+//!
+use std::{any::TypeId, fmt::Display, marker::PhantomData, ops::Deref};
+
+use chrono::{DateTime, Local, SecondsFormat, TimeZone};
 use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 
-use crate::DateTimeDefaultNow;
+use crate::{
+    At, DateTimeDefaultAt, DateTimeDefaultFromNow, DateTimeDefaultMax, DateTimeDefaultMin,
+    DateTimeDefaultNow, DateTimeDefaultNowTruncated, DateTimeDefaultUnix, DateTimeWithDefault,
+    FromNow, Max, Min, NaiveWithDefault, Now, NowTruncated, StartOfDay, StartOfMonth, StartOfWeek,
+    StartOfYear, Unix,
+};
+
+/// Schema of the inner value of a NewType, without the documented default.
+///
+/// Used in `#[schemars(with = "...")]` to get the schema of `DateTime` or of the naive value:
+///
+/// ```
+/// use chrono::Utc;
+/// use datetime_default::{DateTimeDefaultNow, NaiveDateDefaultToday, PlainSchema};
+///
+/// #[derive(Default, schemars::JsonSchema)]
+/// struct A {
+///     #[schemars(with = "PlainSchema<DateTimeDefaultNow<Utc>>")]
+///     updated_at: DateTimeDefaultNow<Utc>,
+///     #[schemars(with = "Option<PlainSchema<NaiveDateDefaultToday<Utc>>>")]
+///     due_on: Option<NaiveDateDefaultToday<Utc>>,
+/// }
+/// ```
+pub struct PlainSchema<T>(PhantomData<T>);
+
+impl<T> JsonSchema for PlainSchema<T>
+where
+    T: Deref,
+    T::Target: JsonSchema,
+{
+    fn is_referenceable() -> bool {
+        T::Target::is_referenceable()
+    }
+
+    fn schema_name() -> String {
+        T::Target::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        T::Target::json_schema(gen)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn schema_name() -> String {
+        "DateTimeDefaultNow".to_owned()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        now_schema(
+            DateTime::<Tz>::json_schema(gen),
+            "Defaults to the current time when omitted.",
+        )
+    }
+}
+
+impl<Tz, const SUBSEC_DIGITS: u16, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for DateTimeDefaultNowTruncated<Tz, SUBSEC_DIGITS, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn schema_name() -> String {
+        // Same schema as `DateTimeDefaultNow`, so the definition is shared.
+        DateTimeDefaultNow::<Tz>::schema_name()
+    }
 
-impl<Tz, const OFFSET_HOURS: i32> JsonSchema for DateTimeDefaultNow<Tz, OFFSET_HOURS>
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        DateTimeDefaultNow::<Tz>::json_schema(gen)
+    }
+}
+
+/// Providers of a calendar anchor of the current time, e.g. the start of the day.
+pub trait CalendarAnchor {
+    /// Suffix of the schema name.
+    const NAME: &'static str;
+    /// The anchor in the description.
+    const DESCRIPTION: &'static str;
+    /// Value of `x-default`.
+    const X_DEFAULT: &'static str;
+}
+
+impl CalendarAnchor for StartOfDay {
+    const NAME: &'static str = "StartOfDay";
+    const DESCRIPTION: &'static str = "start of the current day";
+    const X_DEFAULT: &'static str = "start-of-day";
+}
+
+// The first weekday is not documented, so all weeks share one schema.
+impl<const FIRST_WEEKDAY: u8> CalendarAnchor for StartOfWeek<FIRST_WEEKDAY> {
+    const NAME: &'static str = "StartOfWeek";
+    const DESCRIPTION: &'static str = "start of the current week";
+    const X_DEFAULT: &'static str = "start-of-week";
+}
+
+impl CalendarAnchor for StartOfMonth {
+    const NAME: &'static str = "StartOfMonth";
+    const DESCRIPTION: &'static str = "start of the current month";
+    const X_DEFAULT: &'static str = "start-of-month";
+}
+
+impl CalendarAnchor for StartOfYear {
+    const NAME: &'static str = "StartOfYear";
+    const DESCRIPTION: &'static str = "start of the current year";
+    const X_DEFAULT: &'static str = "start-of-year";
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
+    P: CalendarAnchor,
+{
+    fn schema_name() -> String {
+        format!("DateTimeDefault{}", P::NAME)
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        anchor_schema::<P>(DateTime::<Tz>::json_schema(gen))
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for DateTimeDefaultUnix<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone + 'static,
+    <Tz as TimeZone>::Offset: Copy + std::fmt::Display,
+    Self: Default,
+{
+    fn is_referenceable() -> bool {
+        // The default value depends on the time zone, so the schema is not shared.
+        false
+    }
+
+    fn schema_name() -> String {
+        DateTime::<Tz>::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        schema_with_default::<Tz>(DateTime::<Tz>::json_schema(gen), || {
+            Self::default().to_rfc3339_opts(SecondsFormat::AutoSi, true)
+        })
+    }
+}
+
+impl<Tz, const EPOCH_SECS: i64, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for DateTimeDefaultAt<Tz, EPOCH_SECS, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone + 'static,
+    <Tz as TimeZone>::Offset: Copy + std::fmt::Display,
+    Self: Default,
+{
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        DateTime::<Tz>::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        schema_with_default::<Tz>(DateTime::<Tz>::json_schema(gen), || {
+            Self::default().to_rfc3339_opts(SecondsFormat::AutoSi, true)
+        })
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for DateTimeDefaultMax<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone + 'static,
+    <Tz as TimeZone>::Offset: Copy + std::fmt::Display,
+    Self: Default,
 {
+    fn is_referenceable() -> bool {
+        false
+    }
+
     fn schema_name() -> String {
         DateTime::<Tz>::schema_name()
     }
 
     fn json_schema(gen: &mut SchemaGenerator) -> Schema {
-        DateTime::<Tz>::json_schema(gen)
+        schema_with_default::<Tz>(DateTime::<Tz>::json_schema(gen), || {
+            Self::default().to_rfc3339_opts(SecondsFormat::AutoSi, true)
+        })
     }
 }
 
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for DateTimeDefaultMin<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone + 'static,
+    <Tz as TimeZone>::Offset: Copy + std::fmt::Display,
+    Self: Default,
+{
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        DateTime::<Tz>::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        schema_with_default::<Tz>(DateTime::<Tz>::json_schema(gen), || {
+            Self::default().to_rfc3339_opts(SecondsFormat::AutoSi, true)
+        })
+    }
+}
+
+impl<Tz, const OFFSET_SECS: i64, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for DateTimeDefaultFromNow<Tz, OFFSET_SECS, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn is_referenceable() -> bool {
+        // The description depends on the offset, so the schema is not shared.
+        false
+    }
+
+    fn schema_name() -> String {
+        DateTime::<Tz>::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        from_now_schema(DateTime::<Tz>::json_schema(gen), OFFSET_SECS)
+    }
+}
+
+impl<T, Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for NaiveWithDefault<T, Tz, Now, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: JsonSchema,
+    Tz: TimeZone,
+{
+    fn schema_name() -> String {
+        format!("{}DefaultNow", T::schema_name())
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        now_schema(
+            T::json_schema(gen),
+            "Defaults to the current date or time when omitted.",
+        )
+    }
+}
+
+impl<T, Tz, const SUBSEC_DIGITS: u16, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for NaiveWithDefault<T, Tz, NowTruncated<SUBSEC_DIGITS>, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: JsonSchema,
+    Tz: TimeZone,
+{
+    fn schema_name() -> String {
+        NaiveWithDefault::<T, Tz, Now>::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        NaiveWithDefault::<T, Tz, Now>::json_schema(gen)
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: JsonSchema,
+    Tz: TimeZone,
+    P: CalendarAnchor,
+{
+    fn schema_name() -> String {
+        format!("{}Default{}", T::schema_name(), P::NAME)
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        anchor_schema::<P>(T::json_schema(gen))
+    }
+}
+
+impl<T, Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for NaiveWithDefault<T, Tz, Unix, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: JsonSchema + Display,
+    Tz: TimeZone + 'static,
+    Self: Default,
+{
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        T::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        schema_with_default::<Tz>(T::json_schema(gen), || Self::default().to_string())
+    }
+}
+
+impl<T, Tz, const EPOCH_SECS: i64, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for NaiveWithDefault<T, Tz, At<EPOCH_SECS>, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: JsonSchema + Display,
+    Tz: TimeZone + 'static,
+    Self: Default,
+{
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        T::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        schema_with_default::<Tz>(T::json_schema(gen), || Self::default().to_string())
+    }
+}
+
+impl<T, Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for NaiveWithDefault<T, Tz, Max, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: JsonSchema + Display,
+    Tz: TimeZone + 'static,
+    Self: Default,
+{
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        T::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        schema_with_default::<Tz>(T::json_schema(gen), || Self::default().to_string())
+    }
+}
+
+impl<T, Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for NaiveWithDefault<T, Tz, Min, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: JsonSchema + Display,
+    Tz: TimeZone + 'static,
+    Self: Default,
+{
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        T::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        schema_with_default::<Tz>(T::json_schema(gen), || Self::default().to_string())
+    }
+}
+
+impl<T, Tz, const OFFSET_SECS: i64, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for NaiveWithDefault<T, Tz, FromNow<OFFSET_SECS>, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: JsonSchema,
+    Tz: TimeZone,
+{
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        T::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        from_now_schema(T::json_schema(gen), OFFSET_SECS)
+    }
+}
+
+// The default of `Local` depends on the time zone of the host generating the schema,
+// so it is left out to keep the schema reproducible.
+fn schema_with_default<Tz: 'static>(schema: Schema, default: impl FnOnce() -> String) -> Schema {
+    if TypeId::of::<Tz>() == TypeId::of::<Local>() {
+        return schema;
+    }
+
+    let mut schema = schema.into_object();
+    schema.metadata().default = Some(serde_json::Value::String(default()));
+
+    schema.into()
+}
+
+fn now_schema(schema: Schema, description: &str) -> Schema {
+    let mut schema = schema.into_object();
+    schema.metadata().description = Some(description.to_owned());
+    schema
+        .extensions
+        .insert("x-default".to_owned(), serde_json::Value::from("now"));
+
+    schema.into()
+}
+
+fn anchor_schema<P: CalendarAnchor>(schema: Schema) -> Schema {
+    let mut schema = schema.into_object();
+    schema.metadata().description =
+        Some(format!("Defaults to the {} when omitted.", P::DESCRIPTION));
+    schema.extensions.insert(
+        "x-default".to_owned(),
+        serde_json::Value::from(P::X_DEFAULT),
+    );
+
+    schema.into()
+}
+
+fn from_now_schema(schema: Schema, offset_secs: i64) -> Schema {
+    let mut schema = schema.into_object();
+    schema.metadata().description = Some(format!(
+        "Defaults to {offset_secs} seconds from the current time when omitted."
+    ));
+    schema.extensions.insert(
+        "x-default".to_owned(),
+        serde_json::Value::from(format!("now{offset_secs:+}s")),
+    );
+
+    schema.into()
+}
+
 #[cfg(test)]
 mod test {
-    use chrono::FixedOffset;
+    use chrono::{FixedOffset, Local, NaiveDate};
     use schemars::schema_for;
     use serde_json::json;
 
-    use crate::DateTimeDefaultNow;
+    use crate::{
+        DateTimeDefaultAt, DateTimeDefaultFromNow, DateTimeDefaultMax, DateTimeDefaultMin,
+        DateTimeDefaultNow, DateTimeDefaultNowTruncated, DateTimeDefaultStartOfMonth,
+        DateTimeDefaultStartOfWeek, DateTimeDefaultUnix, NaiveDateDefaultToday, NaiveTimeDefault,
+        NaiveWithDefault, PlainSchema, Unix,
+    };
 
     #[test]
     fn schemas_test() {
@@ -46,17 +477,320 @@ mod test {
                     ],
                     "properties": {
                         "update_at": {
-                            "$ref": "#/definitions/DateTime"
+                            "$ref": "#/definitions/DateTimeDefaultNow"
                         }
                     },
                     "definitions": {
-                        "DateTime": {
+                        "DateTimeDefaultNow": {
+                            "description": "Defaults to the current time when omitted.",
+                            "type": "string",
+                            "format": "date-time",
+                            "x-default": "now"
+                        }
+                    }
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn truncated_schemas_test() {
+        #[allow(dead_code)]
+        #[derive(Default, schemars::JsonSchema)]
+        struct A {
+            update_at: DateTimeDefaultNow<FixedOffset>,
+            synced_at: DateTimeDefaultNowTruncated<FixedOffset, 6>,
+        }
+
+        let schema = schema_for!(A);
+        let value = serde_json::to_value(&schema).unwrap();
+        assert_eq!(
+            value["properties"],
+            json!(
+                {
+                    "update_at": {
+                        "$ref": "#/definitions/DateTimeDefaultNow"
+                    },
+                    "synced_at": {
+                        "$ref": "#/definitions/DateTimeDefaultNow"
+                    }
+                }
+            )
+        );
+        assert_eq!(
+            value["definitions"],
+            json!(
+                {
+                    "DateTimeDefaultNow": {
+                        "description": "Defaults to the current time when omitted.",
+                        "type": "string",
+                        "format": "date-time",
+                        "x-default": "now"
+                    }
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn plain_schemas_test() {
+        #[allow(dead_code)]
+        #[derive(Default, schemars::JsonSchema)]
+        struct A {
+            #[schemars(with = "PlainSchema<DateTimeDefaultNow<FixedOffset>>")]
+            update_at: DateTimeDefaultNow<FixedOffset>,
+            #[schemars(with = "Option<PlainSchema<NaiveDateDefaultToday<FixedOffset, 9>>>")]
+            due_on: Option<NaiveDateDefaultToday<FixedOffset, 9>>,
+        }
+
+        let schema = schema_for!(A);
+        assert_eq!(
+            serde_json::to_value(&schema).unwrap(),
+            json!(
+                {
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "title": "A",
+                    "type": "object",
+                    "required": [
+                        "update_at"
+                    ],
+                    "properties": {
+                        "update_at": {
                             "type": "string",
                             "format": "date-time"
+                        },
+                        "due_on": {
+                            "type": [
+                                "string",
+                                "null"
+                            ],
+                            "format": "date"
+                        }
+                    }
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn unix_schemas_test() {
+        #[allow(dead_code)]
+        #[derive(Default, schemars::JsonSchema)]
+        struct A {
+            created_at: DateTimeDefaultUnix<chrono::Utc>,
+            published_at: DateTimeDefaultUnix<FixedOffset, 9>,
+        }
+
+        let schema = schema_for!(A);
+        assert_eq!(
+            serde_json::to_value(&schema).unwrap(),
+            json!(
+                {
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "title": "A",
+                    "type": "object",
+                    "required": [
+                        "created_at",
+                        "published_at"
+                    ],
+                    "properties": {
+                        "created_at": {
+                            "type": "string",
+                            "format": "date-time",
+                            "default": "1970-01-01T00:00:00Z"
+                        },
+                        "published_at": {
+                            "type": "string",
+                            "format": "date-time",
+                            "default": "1970-01-01T09:00:00+09:00"
                         }
                     }
                 }
             )
         );
     }
+
+    #[test]
+    fn at_schemas_test() {
+        #[allow(dead_code)]
+        #[derive(Default, schemars::JsonSchema)]
+        struct A {
+            valid_from: DateTimeDefaultAt<FixedOffset, 946684800, 9>,
+        }
+
+        let schema = schema_for!(A);
+        assert_eq!(
+            serde_json::to_value(&schema).unwrap()["properties"],
+            json!(
+                {
+                    "valid_from": {
+                        "type": "string",
+                        "format": "date-time",
+                        "default": "2000-01-01T09:00:00+09:00"
+                    }
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn local_schemas_test() {
+        #[allow(dead_code)]
+        #[derive(Default, schemars::JsonSchema)]
+        struct A {
+            created_at: DateTimeDefaultUnix<Local>,
+            valid_to: DateTimeDefaultMax<Local>,
+            since: NaiveWithDefault<NaiveDate, Local, Unix>,
+        }
+
+        let schema = schema_for!(A);
+        assert_eq!(
+            serde_json::to_value(&schema).unwrap()["properties"],
+            json!(
+                {
+                    "created_at": {
+                        "type": "string",
+                        "format": "date-time"
+                    },
+                    "valid_to": {
+                        "type": "string",
+                        "format": "date-time"
+                    },
+                    "since": {
+                        "type": "string",
+                        "format": "date"
+                    }
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn naive_schemas_test() {
+        #[allow(dead_code)]
+        #[derive(Default, schemars::JsonSchema)]
+        struct A {
+            due_on: NaiveDateDefaultToday<FixedOffset, 9>,
+            opens_at: NaiveTimeDefault,
+        }
+
+        let schema = schema_for!(A);
+        let value = serde_json::to_value(&schema).unwrap();
+        assert_eq!(
+            value["properties"],
+            json!(
+                {
+                    "due_on": {
+                        "$ref": "#/definitions/NaiveDateDefaultNow"
+                    },
+                    "opens_at": {
+                        "type": "string",
+                        "format": "partial-date-time",
+                        "default": "00:00:00"
+                    }
+                }
+            )
+        );
+        assert_eq!(
+            value["definitions"]["NaiveDateDefaultNow"],
+            json!(
+                {
+                    "description": "Defaults to the current date or time when omitted.",
+                    "type": "string",
+                    "format": "date",
+                    "x-default": "now"
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn from_now_schemas_test() {
+        #[allow(dead_code)]
+        #[derive(Default, schemars::JsonSchema)]
+        struct A {
+            expires_at: DateTimeDefaultFromNow<chrono::Utc, 86400>,
+        }
+
+        let schema = schema_for!(A);
+        assert_eq!(
+            serde_json::to_value(&schema).unwrap()["properties"],
+            json!(
+                {
+                    "expires_at": {
+                        "description": "Defaults to 86400 seconds from the current time when omitted.",
+                        "type": "string",
+                        "format": "date-time",
+                        "x-default": "now+86400s"
+                    }
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn anchor_schemas_test() {
+        #[allow(dead_code)]
+        #[derive(Default, schemars::JsonSchema)]
+        struct A {
+            week: DateTimeDefaultStartOfWeek<FixedOffset, 6, 9>,
+            month: DateTimeDefaultStartOfMonth<chrono::Utc>,
+        }
+
+        let schema = schema_for!(A);
+        let value = serde_json::to_value(&schema).unwrap();
+        assert_eq!(
+            value["properties"],
+            json!(
+                {
+                    "week": {
+                        "$ref": "#/definitions/DateTimeDefaultStartOfWeek"
+                    },
+                    "month": {
+                        "$ref": "#/definitions/DateTimeDefaultStartOfMonth"
+                    }
+                }
+            )
+        );
+        assert_eq!(
+            value["definitions"]["DateTimeDefaultStartOfWeek"],
+            json!(
+                {
+                    "description": "Defaults to the start of the current week when omitted.",
+                    "type": "string",
+                    "format": "date-time",
+                    "x-default": "start-of-week"
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn min_max_schemas_test() {
+        #[allow(dead_code)]
+        #[derive(Default, schemars::JsonSchema)]
+        struct A {
+            valid_from: DateTimeDefaultMin<chrono::Utc>,
+            valid_to: DateTimeDefaultMax<FixedOffset, 9>,
+        }
+
+        let schema = schema_for!(A);
+        assert_eq!(
+            serde_json::to_value(&schema).unwrap()["properties"],
+            json!(
+                {
+                    "valid_from": {
+                        "type": "string",
+                        "format": "date-time",
+                        "default": "-262144-01-01T00:00:00Z"
+                    },
+                    "valid_to": {
+                        "type": "string",
+                        "format": "date-time",
+                        "default": "+262143-12-31T23:59:59.999999999+09:00"
+                    }
+                }
+            )
+        );
+    }
 }
@@ -0,0 +1,209 @@
+//! Serde support for the `DateTime` newtypes.
+//!
+//! Both newtypes are serialized exactly like the inner `DateTime<Tz>` (RFC 3339),
+//! and `NaiveWithDefault` exactly like its inner naive value.
+//! Deserialized values are converted into the time zone of the NewType.
+//! The submodules are helpers for `#[serde(with = "...")]`.
+//!
+pub mod or_default;
+pub mod ts_microseconds;
+pub mod ts_microseconds_option;
+pub mod ts_milliseconds;
+pub mod ts_milliseconds_option;
+pub mod ts_nanoseconds;
+pub mod ts_nanoseconds_option;
+pub mod ts_seconds;
+pub mod ts_seconds_option;
+
+mod timestamp;
+
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{DateTimeWithDefault, FromUtc, NaiveWithDefault};
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Serialize
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        DateTime::<Tz>::serialize(self, serializer)
+    }
+}
+
+impl<'de, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Deserialize<'de>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Self: FromUtc,
+{
+    /// Deserializes RFC 3339 like `DateTime<FixedOffset>`, then converts into the time zone of the NewType.
+    ///
+    /// The same format as `DateTime<Utc>` and `DateTime<Local>`, and it also covers `NamedTz`.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        DateTime::<FixedOffset>::deserialize(deserializer)
+            .map(|datetime| Self::from_utc(&datetime.with_timezone(&Utc)))
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Serialize
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: Serialize,
+    Tz: TimeZone,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        T::serialize(self, serializer)
+    }
+}
+
+impl<'de, T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Deserialize<'de>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: Deserialize<'de>,
+    Tz: TimeZone,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(NaiveWithDefault::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, FixedOffset, Utc};
+    use serde_json::json;
+
+    use crate::{
+        DateTimeDefaultMax, DateTimeDefaultMin, DateTimeDefaultNow, DateTimeDefaultUnix,
+        NaiveDateDefaultToday, NaiveTimeDefault,
+    };
+
+    #[derive(Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct A {
+        created_at: DateTimeDefaultUnix<Utc>,
+        updated_at: DateTimeDefaultNow<FixedOffset, 9>,
+    }
+
+    #[test]
+    fn serialize_test() {
+        let a = A {
+            created_at: DateTime::parse_from_rfc3339("2022-10-10T23:40:11Z")
+                .unwrap()
+                .with_timezone(&Utc)
+                .into(),
+            updated_at: DateTime::parse_from_rfc3339("2022-10-11T08:40:11+09:00")
+                .unwrap()
+                .into(),
+        };
+
+        assert_eq!(
+            serde_json::to_value(&a).unwrap(),
+            json!(
+                {
+                    "created_at": "2022-10-10T23:40:11Z",
+                    "updated_at": "2022-10-11T08:40:11+09:00"
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn deserialize_test() {
+        let a: A = serde_json::from_value(json!(
+            {
+                "created_at": "1970-01-01T00:00:00Z",
+                "updated_at": "2022-10-11T08:40:11+09:00"
+            }
+        ))
+        .unwrap();
+
+        assert_eq!(a.created_at, DateTimeDefaultUnix::<Utc>::default());
+        assert_eq!(
+            a.updated_at,
+            DateTime::parse_from_rfc3339("2022-10-11T08:40:11+09:00").unwrap()
+        );
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let a = A::default();
+        let json = serde_json::to_string(&a).unwrap();
+
+        assert_eq!(serde_json::from_str::<A>(&json).unwrap(), a);
+    }
+
+    #[test]
+    fn naive_test() {
+        #[derive(Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct B {
+            due_on: NaiveDateDefaultToday<FixedOffset, 9>,
+            opens_at: NaiveTimeDefault,
+        }
+
+        let b = B::default();
+        let value = serde_json::to_value(&b).unwrap();
+
+        assert_eq!(
+            value,
+            json!(
+                {
+                    "due_on": "2022-10-11",
+                    "opens_at": "00:00:00"
+                }
+            )
+        );
+        assert_eq!(serde_json::from_value::<B>(value).unwrap(), b);
+    }
+
+    #[test]
+    fn min_max_test() {
+        #[derive(Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Validity {
+            valid_from: DateTimeDefaultMin<FixedOffset, -5>,
+            valid_to: DateTimeDefaultMax<FixedOffset, 9>,
+        }
+
+        let validity = Validity::default();
+        let value = serde_json::to_value(&validity).unwrap();
+
+        assert_eq!(
+            value,
+            json!(
+                {
+                    "valid_from": "-262144-01-01T00:00:00-05:00",
+                    "valid_to": "+262143-12-31T23:59:59.999999999+09:00"
+                }
+            )
+        );
+        assert_eq!(serde_json::from_value::<Validity>(value).unwrap(), validity);
+    }
+
+    #[test]
+    fn deserialize_converts_into_offset() {
+        let a: A = serde_json::from_value(json!(
+            {
+                "created_at": "2022-10-11T08:40:11+09:00",
+                "updated_at": "2022-10-10T23:40:11Z"
+            }
+        ))
+        .unwrap();
+
+        assert_eq!(a.created_at.to_rfc3339(), "2022-10-10T23:40:11+00:00");
+        assert_eq!(a.updated_at.to_rfc3339(), "2022-10-11T08:40:11+09:00");
+    }
+}
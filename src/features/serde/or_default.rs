@@ -0,0 +1,254 @@
+//! # Deserialize missing values as the default.
+//!
+//! `null` and empty-string values are deserialized as `Default::default()`,
+//! i.e. the current time for `DateTimeDefaultNow` and the UNIX epoch for `DateTimeDefaultUnix`.
+//! Other values are deserialized like `T` itself, so the module works with any serde format.
+//! Empty strings are only recognized in self-describing formats such as JSON.
+//!
+//! A `with` module is not called for absent fields:
+//! they still need `#[serde(default)]` on the field or on the container,
+//! which uses the `#[derive(Default)]` of the struct.
+//!
+//! ```
+//! use chrono::{DateTime, Utc};
+//! use datetime_default::DateTimeDefaultUnix;
+//!
+//! #[derive(Default, serde::Deserialize)]
+//! #[serde(default)]
+//! struct A {
+//!     #[serde(with = "datetime_default::serde::or_default")]
+//!     created_at: DateTimeDefaultUnix<Utc>,
+//!     #[serde(with = "datetime_default::serde::or_default")]
+//!     updated_at: DateTimeDefaultUnix<Utc>,
+//! }
+//!
+//! let a: A = serde_json::from_str(r#"{"created_at": null}"#).unwrap();
+//!
+//! assert_eq!(a.created_at, DateTime::<Utc>::default());
+//! assert_eq!(a.updated_at, DateTime::<Utc>::default());
+//! ```
+//!
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{
+        self,
+        value::{BorrowedStrDeserializer, MapAccessDeserializer, SeqAccessDeserializer},
+        IntoDeserializer, MapAccess, SeqAccess, Visitor,
+    },
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    // Written as the `Some` that `deserialize` reads, which is the bare value in JSON.
+    serializer.serialize_some(value)
+}
+
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    deserializer
+        .deserialize_option(OptionVisitor(PhantomData))
+        .map(Option::unwrap_or_default)
+}
+
+/// Reads `null` as `None`, and any other value as `T`.
+struct OptionVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for OptionVisitor<T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an optional value")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Only self-describing formats can tell an empty string before `T` reads the value.
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ValueVisitor(PhantomData))
+        } else {
+            T::deserialize(deserializer).map(Some)
+        }
+    }
+}
+
+/// Reads an empty string as `None`, and hands any other value to `T`.
+struct ValueVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for ValueVisitor<T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a value or an empty string")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        if v.is_empty() {
+            return Ok(None);
+        }
+        T::deserialize(BorrowedStrDeserializer::new(v)).map(Some)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        if v.is_empty() {
+            return Ok(None);
+        }
+        T::deserialize(v.to_owned().into_deserializer()).map(Some)
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        T::deserialize(v.into_deserializer()).map(Some)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        T::deserialize(v.into_deserializer()).map(Some)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        T::deserialize(v.into_deserializer()).map(Some)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        T::deserialize(v.into_deserializer()).map(Some)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        T::deserialize(SeqAccessDeserializer::new(seq)).map(Some)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        T::deserialize(MapAccessDeserializer::new(map)).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, FixedOffset, Utc};
+    use serde_json::json;
+
+    use crate::{DateTimeDefaultNow, DateTimeDefaultUnix, NaiveDateDefaultToday};
+
+    #[derive(Debug, Default, serde::Deserialize)]
+    #[serde(default)]
+    struct A {
+        #[serde(with = "super")]
+        created_at: DateTimeDefaultUnix<Utc>,
+        #[serde(with = "super")]
+        updated_at: DateTimeDefaultNow<FixedOffset, 9>,
+    }
+
+    #[test]
+    fn missing_test() {
+        let a: A = serde_json::from_value(json!({})).unwrap();
+
+        assert_eq!(a.created_at, DateTimeDefaultUnix::<Utc>::default());
+        assert_eq!(
+            a.updated_at,
+            DateTimeDefaultNow::<FixedOffset, 9>::default()
+        );
+    }
+
+    #[test]
+    fn null_test() {
+        let a: A = serde_json::from_value(json!({"created_at": null, "updated_at": null})).unwrap();
+
+        assert_eq!(a.created_at, DateTimeDefaultUnix::<Utc>::default());
+        assert_eq!(
+            a.updated_at,
+            DateTimeDefaultNow::<FixedOffset, 9>::default()
+        );
+    }
+
+    #[test]
+    fn empty_string_test() {
+        let a: A = serde_json::from_value(json!({"created_at": "", "updated_at": ""})).unwrap();
+
+        assert_eq!(a.created_at, DateTimeDefaultUnix::<Utc>::default());
+        assert_eq!(
+            a.updated_at,
+            DateTimeDefaultNow::<FixedOffset, 9>::default()
+        );
+    }
+
+    #[test]
+    fn value_test() {
+        let a: A =
+            serde_json::from_value(json!({"updated_at": "2022-10-11T08:40:11+09:00"})).unwrap();
+
+        assert_eq!(a.created_at, DateTimeDefaultUnix::<Utc>::default());
+        assert_eq!(
+            a.updated_at,
+            DateTime::parse_from_rfc3339("2022-10-11T08:40:11+09:00").unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_test() {
+        assert!(serde_json::from_value::<A>(json!({"created_at": "invalid"})).is_err());
+    }
+
+    #[test]
+    fn bincode_round_trip_test() {
+        #[derive(Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct B {
+            #[serde(with = "super")]
+            created_at: DateTimeDefaultUnix<Utc>,
+            #[serde(with = "super")]
+            updated_at: DateTimeDefaultNow<FixedOffset, 9>,
+        }
+
+        let b = B::default();
+        let bytes = bincode::serialize(&b).unwrap();
+
+        assert_eq!(bincode::deserialize::<B>(&bytes).unwrap(), b);
+    }
+
+    #[test]
+    fn naive_test() {
+        #[derive(Debug, Default, serde::Deserialize)]
+        struct C {
+            #[serde(default, with = "super")]
+            due_on: NaiveDateDefaultToday<Utc>,
+        }
+
+        let c: C = serde_json::from_value(json!({"due_on": ""})).unwrap();
+        assert_eq!(c.due_on, NaiveDateDefaultToday::<Utc>::default());
+
+        let c: C = serde_json::from_value(json!({"due_on": "2024-05-01"})).unwrap();
+        assert_eq!(c.due_on.to_string(), "2024-05-01");
+
+        let c: C = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(c.due_on, NaiveDateDefaultToday::<Utc>::default());
+    }
+}
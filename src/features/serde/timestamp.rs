@@ -0,0 +1,158 @@
+//! Shared implementation of the `ts_*` modules, which only differ in the unit.
+//!
+use std::ops::Deref;
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{de, ser, Deserialize, Deserializer, Serializer};
+
+use crate::FromUtc;
+
+/// Unit of an integer UNIX timestamp.
+pub trait Unit {
+    const NAME: &'static str;
+
+    /// `None` when the timestamp does not fit in `i64`.
+    fn to_timestamp<Tz: TimeZone>(datetime: &DateTime<Tz>) -> Option<i64>;
+
+    /// `None` when the timestamp is out of the range of `DateTime`.
+    fn from_timestamp(value: i64) -> Option<DateTime<Utc>>;
+}
+
+pub struct Seconds;
+
+impl Unit for Seconds {
+    const NAME: &'static str = "seconds";
+
+    fn to_timestamp<Tz: TimeZone>(datetime: &DateTime<Tz>) -> Option<i64> {
+        Some(datetime.timestamp())
+    }
+
+    fn from_timestamp(value: i64) -> Option<DateTime<Utc>> {
+        Utc.timestamp_opt(value, 0).single()
+    }
+}
+
+pub struct Milliseconds;
+
+impl Unit for Milliseconds {
+    const NAME: &'static str = "milliseconds";
+
+    fn to_timestamp<Tz: TimeZone>(datetime: &DateTime<Tz>) -> Option<i64> {
+        Some(datetime.timestamp_millis())
+    }
+
+    fn from_timestamp(value: i64) -> Option<DateTime<Utc>> {
+        Utc.timestamp_millis_opt(value).single()
+    }
+}
+
+pub struct Microseconds;
+
+impl Unit for Microseconds {
+    const NAME: &'static str = "microseconds";
+
+    fn to_timestamp<Tz: TimeZone>(datetime: &DateTime<Tz>) -> Option<i64> {
+        Some(datetime.timestamp_micros())
+    }
+
+    fn from_timestamp(value: i64) -> Option<DateTime<Utc>> {
+        Utc.timestamp_opt(
+            value.div_euclid(1_000_000),
+            (value.rem_euclid(1_000_000) * 1_000) as u32,
+        )
+        .single()
+    }
+}
+
+pub struct Nanoseconds;
+
+impl Unit for Nanoseconds {
+    const NAME: &'static str = "nanoseconds";
+
+    // `DateTime::timestamp_nanos` panics outside of about 1677 to 2262.
+    fn to_timestamp<Tz: TimeZone>(datetime: &DateTime<Tz>) -> Option<i64> {
+        let secs = datetime.timestamp();
+        let nanos = i64::from(datetime.timestamp_subsec_nanos());
+        if secs < 0 && nanos > 0 {
+            // Keep i64::MIN reachable.
+            (secs + 1)
+                .checked_mul(1_000_000_000)?
+                .checked_add(nanos - 1_000_000_000)
+        } else {
+            secs.checked_mul(1_000_000_000)?.checked_add(nanos)
+        }
+    }
+
+    fn from_timestamp(value: i64) -> Option<DateTime<Utc>> {
+        Some(Utc.timestamp_nanos(value))
+    }
+}
+
+fn to_timestamp<U, Tz, E>(datetime: &DateTime<Tz>) -> Result<i64, E>
+where
+    U: Unit,
+    Tz: TimeZone,
+    E: ser::Error,
+{
+    U::to_timestamp(datetime).ok_or_else(|| {
+        E::custom(format!(
+            "{} UTC is out of range for a timestamp in {}",
+            datetime.naive_utc(),
+            U::NAME
+        ))
+    })
+}
+
+fn from_timestamp<U, T, E>(value: i64) -> Result<T, E>
+where
+    U: Unit,
+    T: FromUtc,
+    E: de::Error,
+{
+    U::from_timestamp(value)
+        .map(|datetime| T::from_utc(&datetime))
+        .ok_or_else(|| E::custom(format!("value is not a legal timestamp: {value}")))
+}
+
+pub fn serialize<U, S, T, Tz>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    U: Unit,
+    S: Serializer,
+    T: Deref<Target = DateTime<Tz>>,
+    Tz: TimeZone,
+{
+    serializer.serialize_i64(to_timestamp::<U, _, _>(value)?)
+}
+
+pub fn deserialize<'de, U, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    U: Unit,
+    D: Deserializer<'de>,
+    T: FromUtc,
+{
+    from_timestamp::<U, _, _>(i64::deserialize(deserializer)?)
+}
+
+pub fn serialize_option<U, S, T, Tz>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    U: Unit,
+    S: Serializer,
+    T: Deref<Target = DateTime<Tz>>,
+    Tz: TimeZone,
+{
+    match value {
+        Some(value) => serializer.serialize_some(&to_timestamp::<U, _, _>(value)?),
+        None => serializer.serialize_none(),
+    }
+}
+
+pub fn deserialize_option<'de, U, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    U: Unit,
+    D: Deserializer<'de>,
+    T: FromUtc,
+{
+    Option::<i64>::deserialize(deserializer)?
+        .map(from_timestamp::<U, _, _>)
+        .transpose()
+}
@@ -0,0 +1,99 @@
+//! # Serialize the NewTypes as an integer UNIX timestamp in milliseconds.
+//!
+//! The deserialized value is converted into the time zone of the NewType.
+//! For the option version, see [`ts_milliseconds_option`](super::ts_milliseconds_option).
+//!
+//! ```
+//! use chrono::FixedOffset;
+//! use datetime_default::{DateTimeDefaultNow, DateTimeDefaultUnix};
+//!
+//! #[derive(Default, serde::Serialize, serde::Deserialize)]
+//! #[serde(default)]
+//! struct Event {
+//!     #[serde(with = "datetime_default::serde::ts_milliseconds")]
+//!     published_at: DateTimeDefaultUnix<FixedOffset, 9>,
+//!     #[serde(with = "datetime_default::serde::ts_milliseconds")]
+//!     received_at: DateTimeDefaultNow<FixedOffset, 9>,
+//! }
+//!
+//! let event: Event = serde_json::from_str(r#"{"received_at": 0}"#).unwrap();
+//!
+//! assert_eq!(event.published_at.to_rfc3339(), "1970-01-01T09:00:00+09:00");
+//! assert_eq!(event.received_at.to_rfc3339(), "1970-01-01T09:00:00+09:00");
+//! ```
+//!
+use std::ops::Deref;
+
+use chrono::{DateTime, TimeZone};
+use serde::{Deserializer, Serializer};
+
+use super::timestamp::{self, Milliseconds};
+use crate::FromUtc;
+
+pub fn serialize<S, T, Tz>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Deref<Target = DateTime<Tz>>,
+    Tz: TimeZone,
+{
+    timestamp::serialize::<Milliseconds, _, _, _>(value, serializer)
+}
+
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromUtc,
+{
+    timestamp::deserialize::<Milliseconds, _, _>(deserializer)
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{FixedOffset, Utc};
+    use serde_json::json;
+
+    use crate::{DateTimeDefaultNow, DateTimeDefaultUnix};
+
+    #[derive(Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+    #[serde(default)]
+    struct A {
+        #[serde(with = "super")]
+        created_at: DateTimeDefaultUnix<Utc>,
+        #[serde(with = "super")]
+        updated_at: DateTimeDefaultNow<FixedOffset, 9>,
+    }
+
+    #[test]
+    fn serialize_test() {
+        let a = A {
+            created_at: DateTimeDefaultUnix::default(),
+            updated_at: DateTimeDefaultNow::default(),
+        };
+
+        assert_eq!(
+            serde_json::to_value(&a).unwrap(),
+            json!({"created_at": 0, "updated_at": 1665445211695_i64})
+        );
+    }
+
+    #[test]
+    fn deserialize_test() {
+        let a: A =
+            serde_json::from_value(json!({"created_at": 0, "updated_at": 1665445211695_i64}))
+                .unwrap();
+
+        assert_eq!(a.created_at, DateTimeDefaultUnix::<Utc>::default());
+        assert_eq!(a.updated_at.to_rfc3339(), "2022-10-11T08:40:11.695+09:00");
+    }
+
+    #[test]
+    fn missing_test() {
+        let a: A = serde_json::from_value(json!({})).unwrap();
+
+        assert_eq!(a.created_at.timestamp(), 0);
+        assert_eq!(
+            a.updated_at,
+            DateTimeDefaultNow::<FixedOffset, 9>::default()
+        );
+    }
+}
@@ -0,0 +1,85 @@
+//! # Serialize `Option` of the NewTypes as an integer UNIX timestamp in nanoseconds.
+//!
+//! `None` is serialized as `null`.
+//! For the non-option version, see [`ts_nanoseconds`](super::ts_nanoseconds).
+//!
+use std::ops::Deref;
+
+use chrono::{DateTime, TimeZone};
+use serde::{Deserializer, Serializer};
+
+use super::timestamp::{self, Nanoseconds};
+use crate::FromUtc;
+
+pub fn serialize<S, T, Tz>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Deref<Target = DateTime<Tz>>,
+    Tz: TimeZone,
+{
+    timestamp::serialize_option::<Nanoseconds, _, _, _>(value, serializer)
+}
+
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromUtc,
+{
+    timestamp::deserialize_option::<Nanoseconds, _, _>(deserializer)
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{FixedOffset, Utc};
+    use serde_json::json;
+
+    use crate::{DateTimeDefaultMin, DateTimeDefaultNow, DateTimeDefaultUnix};
+
+    #[derive(Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct A {
+        #[serde(default, with = "super")]
+        created_at: Option<DateTimeDefaultUnix<Utc>>,
+        #[serde(default, with = "super")]
+        updated_at: Option<DateTimeDefaultNow<FixedOffset, 9>>,
+    }
+
+    #[test]
+    fn serialize_test() {
+        let a = A {
+            created_at: Some(DateTimeDefaultUnix::default()),
+            updated_at: None,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&a).unwrap(),
+            json!({"created_at": 0, "updated_at": null})
+        );
+    }
+
+    #[test]
+    fn deserialize_test() {
+        let a: A = serde_json::from_value(json!({"created_at": 0, "updated_at": null})).unwrap();
+
+        assert_eq!(
+            a,
+            A {
+                created_at: Some(DateTimeDefaultUnix::default()),
+                updated_at: None,
+            }
+        );
+    }
+
+    #[test]
+    fn out_of_range_test() {
+        #[derive(serde::Serialize)]
+        struct B {
+            #[serde(with = "super")]
+            valid_from: Option<DateTimeDefaultMin<Utc>>,
+        }
+
+        assert!(serde_json::to_value(B {
+            valid_from: Some(DateTimeDefaultMin::default()),
+        })
+        .is_err());
+    }
+}
@@ -0,0 +1,176 @@
+//! sqlx support for the `DateTime` newtypes.
+//!
+//! `Type`, `Encode` and `Decode` are delegated to the inner `DateTime<Tz>`,
+//! so the NewTypes are supported by every database that supports `DateTime<Tz>`.
+//! Decoded values are converted into the time zone of the NewType.
+//! `NaiveWithDefault` is delegated to its inner naive value the same way.
+//!
+use chrono::{DateTime, TimeZone, Utc};
+use sqlx::{
+    database::{HasArguments, HasValueRef},
+    encode::IsNull,
+    error::BoxDynError,
+    Database, Decode, Encode, Type,
+};
+
+use crate::{DateTimeWithDefault, FromUtc, NaiveWithDefault};
+
+impl<DB, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Type<DB>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    DB: Database,
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    DateTime<Tz>: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        DateTime::<Tz>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        DateTime::<Tz>::compatible(ty)
+    }
+}
+
+impl<'q, DB, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Encode<'q, DB>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    DB: Database,
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    DateTime<Tz>: Encode<'q, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+        DateTime::<Tz>::encode_by_ref(self, buf)
+    }
+
+    fn produces(&self) -> Option<DB::TypeInfo> {
+        DateTime::<Tz>::produces(self)
+    }
+
+    fn size_hint(&self) -> usize {
+        DateTime::<Tz>::size_hint(self)
+    }
+}
+
+impl<'r, DB, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Decode<'r, DB>
+    for DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    DB: Database,
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    DateTime<Tz>: Decode<'r, DB>,
+    Self: FromUtc,
+{
+    fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        DateTime::<Tz>::decode(value).map(|datetime| Self::from_utc(&datetime.with_timezone(&Utc)))
+    }
+}
+
+impl<DB, T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Type<DB>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    DB: Database,
+    T: Type<DB>,
+    Tz: TimeZone,
+{
+    fn type_info() -> DB::TypeInfo {
+        T::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        T::compatible(ty)
+    }
+}
+
+impl<'q, DB, T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Encode<'q, DB>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    DB: Database,
+    T: Encode<'q, DB>,
+    Tz: TimeZone,
+{
+    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+        T::encode_by_ref(self, buf)
+    }
+
+    fn produces(&self) -> Option<DB::TypeInfo> {
+        T::produces(self)
+    }
+
+    fn size_hint(&self) -> usize {
+        T::size_hint(self)
+    }
+}
+
+impl<'r, DB, T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Decode<'r, DB>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    DB: Database,
+    T: Decode<'r, DB>,
+    Tz: TimeZone,
+{
+    fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        T::decode(value).map(NaiveWithDefault::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{FixedOffset, Local, Utc};
+    use sqlx::{Decode, Encode, MySql, Postgres, Row, Sqlite, SqlitePool, Type};
+
+    use crate::{
+        DateTimeDefaultAt, DateTimeDefaultNow, DateTimeDefaultUnix, NaiveDateDefaultToday,
+        NaiveDateTimeDefaultUnix, NaiveTimeDefault,
+    };
+
+    fn assert_sqlx<DB, T>()
+    where
+        DB: sqlx::Database,
+        T: Type<DB> + for<'q> Encode<'q, DB> + for<'r> Decode<'r, DB>,
+    {
+    }
+
+    #[test]
+    fn databases_test() {
+        assert_sqlx::<Postgres, DateTimeDefaultNow<Utc>>();
+        assert_sqlx::<Postgres, DateTimeDefaultUnix<FixedOffset, 9>>();
+        assert_sqlx::<Postgres, DateTimeDefaultAt<Local, 946684800>>();
+        assert_sqlx::<MySql, DateTimeDefaultNow<Utc>>();
+        assert_sqlx::<MySql, DateTimeDefaultUnix<Local>>();
+        assert_sqlx::<Sqlite, DateTimeDefaultNow<FixedOffset, 9>>();
+        assert_sqlx::<Sqlite, DateTimeDefaultAt<Utc, 946684800>>();
+        assert_sqlx::<Postgres, NaiveDateDefaultToday<FixedOffset, 9>>();
+        assert_sqlx::<MySql, NaiveDateTimeDefaultUnix>();
+        assert_sqlx::<Sqlite, NaiveTimeDefault>();
+    }
+
+    #[tokio::test]
+    async fn sqlite_test() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+
+        sqlx::query(
+            "CREATE TABLE test (created_at DATETIME NOT NULL, updated_at DATETIME NOT NULL)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO test VALUES (?, ?)")
+            .bind(DateTimeDefaultUnix::<Utc>::default())
+            .bind(DateTimeDefaultNow::<FixedOffset, 9>::default())
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let row = sqlx::query("SELECT created_at, updated_at FROM test")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let created_at: DateTimeDefaultUnix<FixedOffset, 9> = row.get("created_at");
+        let updated_at: DateTimeDefaultNow<Utc> = row.get("updated_at");
+
+        assert_eq!(created_at.to_rfc3339(), "1970-01-01T09:00:00+09:00");
+        assert_eq!(updated_at, DateTimeDefaultNow::<Utc>::default());
+    }
+}
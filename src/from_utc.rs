@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+
+/// # Conversion from a UTC instant into the time zone of the NewType.
+///
+/// `FixedOffset` NewTypes are converted into the zone of `OFFSET_HOURS` and `OFFSET_MINUTES`.
+///
+/// ```
+/// use chrono::{DateTime, FixedOffset, Utc};
+/// use datetime_default::{DateTimeDefaultUnix, FromUtc};
+///
+/// assert_eq!(
+///     DateTimeDefaultUnix::<FixedOffset, 9>::from_utc(&DateTime::<Utc>::default()).to_rfc3339(),
+///     "1970-01-01T09:00:00+09:00"
+/// );
+/// ```
+pub trait FromUtc {
+    fn from_utc(datetime: &DateTime<Utc>) -> Self;
+}
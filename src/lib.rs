@@ -33,6 +33,117 @@
 //! );
 //! ```
 //!
+//! ### DateTimeDefaultAt
+//! DateTime with user specified UNIX timestamp as default.
+//!
+//! ```
+//! use chrono::{Utc, TimeZone};
+//! use datetime_default::DateTimeDefaultAt;
+//!
+//! assert_eq!(
+//!     DateTimeDefaultAt::<Utc, 946684800>::default(),
+//!     Utc.datetime_from_str("2000/1/1 00:00:00", "%Y/%m/%d %H:%M:%S").unwrap()
+//! );
+//! ```
+//!
+//! ### DateTimeDefaultMin / Max
+//! DateTime with the earliest or latest time of chrono as default, for open-ended validity ranges.
+//! The values are clamped so that the local time in `OFFSET_HOURS` does not overflow.
+//! `END_OF_TIME_SECS` and `BEGINNING_OF_TIME_SECS` are sentinels for `DateTimeDefaultAt` within the range of most databases.
+//!
+//! ```
+//! use chrono::{DateTime, Utc};
+//! use datetime_default::{DateTimeDefaultAt, DateTimeDefaultMin, END_OF_TIME_SECS};
+//!
+//! #[derive(Default)]
+//! struct Validity {
+//!     valid_from: DateTimeDefaultMin<Utc>,
+//!     valid_to: DateTimeDefaultAt<Utc, END_OF_TIME_SECS>,
+//! }
+//!
+//! let validity = Validity::default();
+//!
+//! assert_eq!(validity.valid_from, DateTime::<Utc>::MIN_UTC);
+//! assert_eq!(validity.valid_to.to_rfc3339(), "9999-12-31T23:59:59+00:00");
+//! ```
+//!
+//! ### DateTimeDefaultNowTruncated
+//! DateTime with the current time truncated to `SUBSEC_DIGITS` fractional digits as default,
+//! so values survive a round trip through storage with seconds, milliseconds or microseconds precision.
+//!
+//! ```
+//! use chrono::{Timelike, Utc};
+//! use datetime_default::DateTimeDefaultNowTruncated;
+//!
+//! assert_eq!(DateTimeDefaultNowTruncated::<Utc, 6>::default().nanosecond() % 1000, 0);
+//! ```
+//!
+//! ### DateTimeDefaultFromNow
+//! DateTime with the current time plus `OFFSET_SECS` seconds as default, e.g. for expiry fields.
+//!
+//! ```
+//! use chrono::{Duration, Utc};
+//! use datetime_default::{DateTimeDefaultFromNow, DateTimeDefaultNow};
+//!
+//! let expires_at = DateTimeDefaultFromNow::<Utc, 86400>::default();
+//!
+//! assert!(expires_at > *DateTimeDefaultNow::<Utc>::default() + Duration::hours(23));
+//! ```
+//!
+//! ### DateTimeDefaultStartOfDay / Week / Month / Year
+//! DateTime with the start of the current day, week, month or year in the time zone of the NewType as default.
+//! Weeks start on Monday, or on the weekday given by `FIRST_WEEKDAY` days from Monday.
+//!
+//! ```
+//! use chrono::{Datelike, Timelike, Utc, Weekday};
+//! use datetime_default::{DateTimeDefaultStartOfMonth, DateTimeDefaultStartOfWeek};
+//!
+//! let week = DateTimeDefaultStartOfWeek::<Utc, 6>::default();
+//! let month = DateTimeDefaultStartOfMonth::<Utc>::default();
+//!
+//! assert_eq!(week.weekday(), Weekday::Sun);
+//! assert_eq!((month.day(), month.hour()), (1, 0));
+//! ```
+//!
+//! ### DateTimeWithDefault
+//! DateTime with the default given by a `DefaultProvider`.
+//! The NewTypes above are aliases with the `Now`, `Unix` and `At` providers.
+//!
+//! ```
+//! use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+//! use datetime_default::{DateTimeWithDefault, DefaultProvider};
+//!
+//! struct Y2K;
+//!
+//! impl DefaultProvider for Y2K {
+//!     fn default_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz> {
+//!         let y2k = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+//!
+//!         tz.from_utc_datetime(&y2k)
+//!     }
+//! }
+//!
+//! assert_eq!(
+//!     DateTimeWithDefault::<Utc, Y2K>::default(),
+//!     Utc.datetime_from_str("2000/1/1 00:00:00", "%Y/%m/%d %H:%M:%S").unwrap()
+//! );
+//! ```
+//!
+//! ### NaiveWithDefault
+//! Naive date and time with the default taken in a type-selected zone:
+//! `NaiveDateDefaultToday`, `NaiveDateTimeDefaultNow`, `NaiveDateDefaultUnix`,
+//! `NaiveDateTimeDefaultUnix` and `NaiveTimeDefault`.
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use datetime_default::NaiveDateDefaultUnix;
+//!
+//! assert_eq!(
+//!     NaiveDateDefaultUnix::default(),
+//!     NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()
+//! );
+//! ```
+//!
 //! ### Default Datetime with TimeZone
 //!
 //! ```
@@ -45,11 +156,110 @@
 //! );
 //! ```
 //!
+//! Offsets with minutes are given by `OFFSET_MINUTES`, with the same sign as `OFFSET_HOURS`.
+//!
+//! ```
+//! use chrono::{DateTime, FixedOffset, TimeZone};
+//! use datetime_default::DateTimeDefaultUnix;
+//!
+//! assert_eq!(
+//!     DateTimeDefaultUnix::<FixedOffset, 5, 30>::default(),
+//!     DateTime::parse_from_rfc3339("1970-01-01T05:30:00+05:30").unwrap()
+//! );
+//! ```
+//!
+pub mod clock;
 mod const_assert;
 mod datetime_default;
+mod datetime_default_at;
+mod datetime_default_from_now;
+mod datetime_default_min_max;
 mod datetime_default_now;
+mod datetime_default_now_truncated;
+mod datetime_default_start_of;
 mod datetime_default_unix;
+mod datetime_with_default;
+mod default_provider;
 mod features;
+mod from_utc;
+mod naive_date_default;
+mod naive_datetime_default;
+mod naive_time_default;
+mod naive_with_default;
+mod parse_lenient;
 
+pub use clock::same_now;
+pub use datetime_default_at::DateTimeDefaultAt;
+pub use datetime_default_from_now::DateTimeDefaultFromNow;
+pub use datetime_default_min_max::{
+    DateTimeDefaultMax, DateTimeDefaultMin, BEGINNING_OF_TIME_SECS, END_OF_TIME_SECS,
+};
 pub use datetime_default_now::DateTimeDefaultNow;
+pub use datetime_default_now_truncated::DateTimeDefaultNowTruncated;
+pub use datetime_default_start_of::{
+    DateTimeDefaultStartOfDay, DateTimeDefaultStartOfMonth, DateTimeDefaultStartOfWeek,
+    DateTimeDefaultStartOfYear,
+};
 pub use datetime_default_unix::DateTimeDefaultUnix;
+pub use datetime_with_default::{DateTimeWithDefault, ParseStrictError};
+pub use default_provider::{
+    At, DefaultProvider, FromNow, Max, Min, Now, NowTruncated, StartOfDay, StartOfMonth,
+    StartOfWeek, StartOfYear, Unix,
+};
+pub use from_utc::FromUtc;
+pub use naive_date_default::{NaiveDateDefaultToday, NaiveDateDefaultUnix};
+pub use naive_datetime_default::{NaiveDateTimeDefaultNow, NaiveDateTimeDefaultUnix};
+pub use naive_time_default::NaiveTimeDefault;
+pub use naive_with_default::{NaivePart, NaiveWithDefault};
+pub use parse_lenient::ParseLenientError;
+
+#[cfg(feature = "chrono-tz")]
+pub use features::chrono_tz::{NamedTimeZone, NamedTz, NamedTzOffset, ParseNamedTzError};
+
+#[cfg(feature = "schemars")]
+pub use features::schemars::PlainSchema;
+
+/// `datetime_default!` expands to the `DateTimeDefaultAt` type of the given instant.
+///
+/// ```
+/// use chrono::{DateTime, FixedOffset, Utc};
+/// use datetime_default::datetime_default;
+///
+/// #[derive(Default)]
+/// struct A {
+///     valid_from: datetime_default!("2000-01-01T00:00:00Z"),
+///     valid_to: datetime_default!("9999-12-31T23:59:59+09:00"),
+/// }
+///
+/// let a = A::default();
+///
+/// assert_eq!(
+///     a.valid_from,
+///     DateTime::parse_from_rfc3339("2000-01-01T00:00:00Z").unwrap().with_timezone(&Utc)
+/// );
+/// assert_eq!(a.valid_to.to_rfc3339(), "9999-12-31T23:59:59+09:00");
+/// ```
+///
+/// Invalid literals are rejected at compile time.
+///
+/// ```compile_fail
+/// use datetime_default::datetime_default;
+///
+/// #[derive(Default)]
+/// struct A {
+///     valid_from: datetime_default!("2000-13-01T00:00:00Z"),
+/// }
+/// ```
+#[cfg(feature = "macros")]
+pub use datetime_default_macros::datetime_default;
+
+// Lets `datetime_default!` expand to chrono types without a `chrono` dependency in the caller.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use chrono as __chrono;
+
+#[cfg(feature = "rusqlite")]
+pub use features::rusqlite;
+
+#[cfg(feature = "serde")]
+pub use features::serde;
@@ -0,0 +1,82 @@
+use chrono::{NaiveDate, Utc};
+
+use crate::{NaiveWithDefault, Now, Unix};
+
+/// # NaiveDate with today as default.
+///
+/// Today is the date of the current time in the time zone `Tz`.
+///
+/// ```should_panic
+/// use chrono::{Local, NaiveDate};
+/// use datetime_default::NaiveDateDefaultToday;
+///
+/// assert_eq!(
+///     NaiveDateDefaultToday::<Local>::default(),
+///     NaiveDate::from_ymd_opt(2022, 10, 10).unwrap()
+/// );
+/// ```
+pub type NaiveDateDefaultToday<Tz, const OFFSET_HOURS: i32 = 0, const OFFSET_MINUTES: i32 = 0> =
+    NaiveWithDefault<NaiveDate, Tz, Now, OFFSET_HOURS, OFFSET_MINUTES>;
+
+/// # NaiveDate with the date of UNIX epoch as default.
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use datetime_default::NaiveDateDefaultUnix;
+///
+/// assert_eq!(
+///     NaiveDateDefaultUnix::default(),
+///     NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()
+/// );
+/// ```
+pub type NaiveDateDefaultUnix = NaiveWithDefault<NaiveDate, Utc, Unix>;
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::{FixedOffset, Local, NaiveDate, Utc};
+
+    use crate::{NaiveDateDefaultToday, NaiveDateDefaultUnix};
+
+    #[derive(Default)]
+    struct Test {
+        business_date: NaiveDateDefaultToday<Utc>,
+        opened_on: NaiveDateDefaultUnix,
+    }
+
+    #[test]
+    fn default_derive_struct() {
+        let test = Test::default();
+
+        assert_eq!(test.business_date.to_string(), "2022-10-10");
+        assert_eq!(test.opened_on.to_string(), "1970-01-01");
+    }
+
+    #[test]
+    fn fixed_offset() {
+        assert_eq!(
+            NaiveDateDefaultToday::<FixedOffset, 9>::default().to_string(),
+            "2022-10-11"
+        );
+        assert_eq!(
+            NaiveDateDefaultToday::<FixedOffset, -5, -30>::default().to_string(),
+            "2022-10-10"
+        );
+    }
+
+    #[test]
+    fn local() {
+        assert_eq!(
+            NaiveDateDefaultToday::<Local>::default(),
+            crate::clock::now().with_timezone(&Local).date_naive()
+        );
+    }
+
+    #[test]
+    fn convert_from() {
+        let date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+
+        assert_eq!(NaiveDateDefaultToday::<Utc>::from(date), date);
+        assert_eq!(date, NaiveDateDefaultUnix::from(date));
+    }
+}
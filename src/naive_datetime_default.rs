@@ -0,0 +1,81 @@
+use chrono::{NaiveDateTime, Utc};
+
+use crate::{NaiveWithDefault, Now, Unix};
+
+/// # NaiveDateTime with current local time as default.
+///
+/// The current time is taken as the wall clock time of the time zone `Tz`.
+///
+/// ```should_panic
+/// use chrono::{Local, NaiveDateTime};
+/// use datetime_default::NaiveDateTimeDefaultNow;
+///
+/// assert_eq!(
+///     NaiveDateTimeDefaultNow::<Local>::default(),
+///     NaiveDateTime::parse_from_str("2022-10-10 23:40:11.695164300", "%F %T%.9f").unwrap()
+/// );
+/// ```
+pub type NaiveDateTimeDefaultNow<Tz, const OFFSET_HOURS: i32 = 0, const OFFSET_MINUTES: i32 = 0> =
+    NaiveWithDefault<NaiveDateTime, Tz, Now, OFFSET_HOURS, OFFSET_MINUTES>;
+
+/// # NaiveDateTime with UNIX epoch as default.
+///
+/// ```
+/// use chrono::NaiveDateTime;
+/// use datetime_default::NaiveDateTimeDefaultUnix;
+///
+/// assert_eq!(
+///     NaiveDateTimeDefaultUnix::default(),
+///     NaiveDateTime::from_timestamp_opt(0, 0).unwrap()
+/// );
+/// ```
+pub type NaiveDateTimeDefaultUnix = NaiveWithDefault<NaiveDateTime, Utc, Unix>;
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::{FixedOffset, Local, NaiveDateTime, Utc};
+
+    use crate::{NaiveDateTimeDefaultNow, NaiveDateTimeDefaultUnix};
+
+    #[derive(Default)]
+    struct Test {
+        recorded_at: NaiveDateTimeDefaultNow<Utc>,
+        imported_at: NaiveDateTimeDefaultUnix,
+    }
+
+    #[test]
+    fn default_derive_struct() {
+        let test = Test::default();
+
+        assert_eq!(
+            test.recorded_at.to_string(),
+            "2022-10-10 23:40:11.695164300"
+        );
+        assert_eq!(test.imported_at.to_string(), "1970-01-01 00:00:00");
+    }
+
+    #[test]
+    fn fixed_offset() {
+        assert_eq!(
+            NaiveDateTimeDefaultNow::<FixedOffset, 9>::default().to_string(),
+            "2022-10-11 08:40:11.695164300"
+        );
+    }
+
+    #[test]
+    fn local() {
+        assert_eq!(
+            NaiveDateTimeDefaultNow::<Local>::default(),
+            crate::clock::now().with_timezone(&Local).naive_local()
+        );
+    }
+
+    #[test]
+    fn convert_into() {
+        let datetime = NaiveDateTime::from_timestamp_opt(946684800, 0).unwrap();
+        let converted: NaiveDateTimeDefaultUnix = datetime.into();
+
+        assert_eq!(converted, datetime);
+    }
+}
@@ -0,0 +1,41 @@
+use chrono::{NaiveTime, Utc};
+
+use crate::{NaiveWithDefault, Unix};
+
+/// # NaiveTime with midnight as default.
+///
+/// ```
+/// use chrono::NaiveTime;
+/// use datetime_default::NaiveTimeDefault;
+///
+/// assert_eq!(
+///     NaiveTimeDefault::default(),
+///     NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+/// );
+/// ```
+pub type NaiveTimeDefault = NaiveWithDefault<NaiveTime, Utc, Unix>;
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::NaiveTime;
+
+    use crate::NaiveTimeDefault;
+
+    #[derive(Default)]
+    struct Test {
+        opens_at: NaiveTimeDefault,
+    }
+
+    #[test]
+    fn default_derive_struct() {
+        assert_eq!(Test::default().opens_at.to_string(), "00:00:00");
+    }
+
+    #[test]
+    fn parse() {
+        let time: NaiveTimeDefault = "09:30:00".parse().unwrap();
+
+        assert_eq!(time, NaiveTime::from_hms_opt(9, 30, 0).unwrap());
+    }
+}
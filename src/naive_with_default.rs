@@ -0,0 +1,830 @@
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    hash::Hash,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use chrono::{
+    DateTime, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+};
+
+use crate::{
+    const_assert::{AssertOffsetHours, AssertOffsetMinutes, AssertZeroOffset},
+    DefaultProvider, FromUtc, Now, Unix,
+};
+
+/// # Naive part of a `DateTime`, as kept by `NaiveWithDefault`.
+pub trait NaivePart {
+    fn from_local<Tz: TimeZone>(datetime: &DateTime<Tz>) -> Self;
+}
+
+impl NaivePart for NaiveDate {
+    fn from_local<Tz: TimeZone>(datetime: &DateTime<Tz>) -> Self {
+        datetime.naive_local().date()
+    }
+}
+
+impl NaivePart for NaiveDateTime {
+    fn from_local<Tz: TimeZone>(datetime: &DateTime<Tz>) -> Self {
+        datetime.naive_local()
+    }
+}
+
+impl NaivePart for NaiveTime {
+    fn from_local<Tz: TimeZone>(datetime: &DateTime<Tz>) -> Self {
+        datetime.naive_local().time()
+    }
+}
+
+/// # Naive date or time with the default given by a [`DefaultProvider`].
+///
+/// The default of the provider is taken in the time zone `Tz`,
+/// then only its local [`NaivePart`] `T` is kept.
+/// `NaiveDateDefaultToday`, `NaiveDateTimeDefaultNow` and `NaiveTimeDefault` are aliases of this type.
+///
+/// ```
+/// use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+/// use datetime_default::{clock, NaiveDateDefaultToday};
+///
+/// let now = Utc.datetime_from_str("2022/10/10 23:40:11", "%Y/%m/%d %H:%M:%S").unwrap();
+///
+/// clock::with_frozen_now(now, || {
+///     assert_eq!(
+///         NaiveDateDefaultToday::<Utc>::default(),
+///         NaiveDate::from_ymd_opt(2022, 10, 10).unwrap()
+///     );
+///     assert_eq!(
+///         NaiveDateDefaultToday::<FixedOffset, 9>::default(),
+///         NaiveDate::from_ymd_opt(2022, 10, 11).unwrap()
+///     );
+/// });
+/// ```
+pub struct NaiveWithDefault<T, Tz, P, const OFFSET_HOURS: i32 = 0, const OFFSET_MINUTES: i32 = 0>(
+    T,
+    PhantomData<(Tz, P)>,
+)
+where
+    Tz: TimeZone;
+
+impl<T, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Default
+    for NaiveWithDefault<T, FixedOffset, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: NaivePart,
+    P: DefaultProvider,
+{
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn default() -> Self {
+        AssertOffsetHours::<-24, OFFSET_HOURS, 24>::OK;
+        AssertOffsetMinutes::<OFFSET_HOURS, OFFSET_MINUTES>::OK;
+
+        Self::from(T::from_local(&P::default_in(
+            &FixedOffset::east_opt(OFFSET_HOURS * 3600 + OFFSET_MINUTES * 60).unwrap(),
+        )))
+    }
+}
+
+impl<T, P> Default for NaiveWithDefault<T, Local, P, 0, 0>
+where
+    T: NaivePart,
+    P: DefaultProvider,
+{
+    fn default() -> Self {
+        Self::from(T::from_local(&P::default_in(&Local)))
+    }
+}
+
+impl<T, P> Default for NaiveWithDefault<T, Utc, P, 0, 0>
+where
+    T: NaivePart,
+    P: DefaultProvider,
+{
+    fn default() -> Self {
+        Self::from(T::from_local(&P::default_in(&Utc)))
+    }
+}
+
+// The derives would require the zone and the provider to implement the traits too.
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::fmt::Debug
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: std::fmt::Debug,
+    Tz: TimeZone,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("NaiveWithDefault").field(&self.0).finish()
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Clone
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: Copy,
+    Tz: TimeZone,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Copy
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: Copy,
+    Tz: TimeZone,
+{
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> PartialEq
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: PartialEq,
+    Tz: TimeZone,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Eq
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: Eq,
+    Tz: TimeZone,
+{
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> PartialOrd
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: PartialOrd,
+    Tz: TimeZone,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Ord
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: Ord,
+    Tz: TimeZone,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Hash
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: Hash,
+    Tz: TimeZone,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Deref
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> From<T>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+{
+    fn from(naive: T) -> Self {
+        Self(naive, PhantomData)
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+{
+    /// Unwraps the inner naive value.
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use datetime_default::NaiveDateDefaultUnix;
+    ///
+    /// let date: NaiveDate = NaiveDateDefaultUnix::default().into_inner();
+    ///
+    /// assert_eq!(date, NaiveDate::from_ymd_opt(1970, 1, 1).unwrap());
+    /// ```
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> DerefMut
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> AsRef<T>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+{
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> AsMut<T>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+{
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+// `Eq`, `Ord` and `Hash` delegate to the inner naive value, as `Borrow` requires.
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Borrow<T>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+{
+    fn borrow(&self) -> &T {
+        &self.0
+    }
+}
+
+// A generic `From<NaiveWithDefault<T, ..>> for T` is not allowed by the orphan rules.
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    From<NaiveWithDefault<NaiveDate, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> for NaiveDate
+where
+    Tz: TimeZone,
+{
+    fn from(naive: NaiveWithDefault<NaiveDate, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>) -> Self {
+        naive.0
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    From<NaiveWithDefault<NaiveDateTime, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> for NaiveDateTime
+where
+    Tz: TimeZone,
+{
+    fn from(naive: NaiveWithDefault<NaiveDateTime, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>) -> Self {
+        naive.0
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    From<NaiveWithDefault<NaiveTime, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>> for NaiveTime
+where
+    Tz: TimeZone,
+{
+    fn from(naive: NaiveWithDefault<NaiveTime, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>) -> Self {
+        naive.0
+    }
+}
+
+impl<T, Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    From<NaiveWithDefault<T, Tz, Now, OFFSET_HOURS, OFFSET_MINUTES>>
+    for NaiveWithDefault<T, Tz, Unix, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+{
+    fn from(naive: NaiveWithDefault<T, Tz, Now, OFFSET_HOURS, OFFSET_MINUTES>) -> Self {
+        Self::from(naive.0)
+    }
+}
+
+impl<T, Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    From<NaiveWithDefault<T, Tz, Unix, OFFSET_HOURS, OFFSET_MINUTES>>
+    for NaiveWithDefault<T, Tz, Now, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+{
+    fn from(naive: NaiveWithDefault<T, Tz, Unix, OFFSET_HOURS, OFFSET_MINUTES>) -> Self {
+        Self::from(naive.0)
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::str::FromStr
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: std::str::FromStr,
+    Tz: TimeZone,
+{
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        T::from_str(s).map(NaiveWithDefault::from)
+    }
+}
+
+impl<T, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromUtc
+    for NaiveWithDefault<T, FixedOffset, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: NaivePart,
+{
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn from_utc(datetime: &DateTime<Utc>) -> Self {
+        AssertOffsetHours::<-24, OFFSET_HOURS, 24>::OK;
+        AssertOffsetMinutes::<OFFSET_HOURS, OFFSET_MINUTES>::OK;
+
+        Self::from(T::from_local(&datetime.with_timezone(
+            &FixedOffset::east_opt(OFFSET_HOURS * 3600 + OFFSET_MINUTES * 60).unwrap(),
+        )))
+    }
+}
+
+impl<T, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromUtc
+    for NaiveWithDefault<T, Local, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: NaivePart,
+{
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn from_utc(datetime: &DateTime<Utc>) -> Self {
+        AssertZeroOffset::<OFFSET_HOURS, OFFSET_MINUTES>::OK;
+
+        Self::from(T::from_local(&datetime.with_timezone(&Local)))
+    }
+}
+
+impl<T, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromUtc
+    for NaiveWithDefault<T, Utc, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: NaivePart,
+{
+    #[allow(path_statements)]
+    #[allow(clippy::no_effect)]
+    fn from_utc(datetime: &DateTime<Utc>) -> Self {
+        AssertZeroOffset::<OFFSET_HOURS, OFFSET_MINUTES>::OK;
+
+        Self::from(T::from_local(datetime))
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::cmp::PartialEq<T>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: PartialEq,
+    Tz: TimeZone,
+{
+    fn eq(&self, other: &T) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::cmp::PartialOrd<T>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: PartialOrd,
+    Tz: TimeZone,
+{
+    fn partial_cmp(&self, other: &T) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    std::cmp::PartialEq<NaiveWithDefault<NaiveDate, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>>
+    for NaiveDate
+where
+    Tz: TimeZone,
+{
+    fn eq(&self, other: &NaiveWithDefault<NaiveDate, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>) -> bool {
+        self.eq(&other.0)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    std::cmp::PartialOrd<NaiveWithDefault<NaiveDate, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>>
+    for NaiveDate
+where
+    Tz: TimeZone,
+{
+    fn partial_cmp(
+        &self,
+        other: &NaiveWithDefault<NaiveDate, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>,
+    ) -> Option<std::cmp::Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    std::cmp::PartialEq<NaiveWithDefault<NaiveDateTime, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>>
+    for NaiveDateTime
+where
+    Tz: TimeZone,
+{
+    fn eq(
+        &self,
+        other: &NaiveWithDefault<NaiveDateTime, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>,
+    ) -> bool {
+        self.eq(&other.0)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    std::cmp::PartialOrd<NaiveWithDefault<NaiveDateTime, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>>
+    for NaiveDateTime
+where
+    Tz: TimeZone,
+{
+    fn partial_cmp(
+        &self,
+        other: &NaiveWithDefault<NaiveDateTime, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>,
+    ) -> Option<std::cmp::Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    std::cmp::PartialEq<NaiveWithDefault<NaiveTime, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>>
+    for NaiveTime
+where
+    Tz: TimeZone,
+{
+    fn eq(&self, other: &NaiveWithDefault<NaiveTime, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>) -> bool {
+        self.eq(&other.0)
+    }
+}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    std::cmp::PartialOrd<NaiveWithDefault<NaiveTime, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>>
+    for NaiveTime
+where
+    Tz: TimeZone,
+{
+    fn partial_cmp(
+        &self,
+        other: &NaiveWithDefault<NaiveTime, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>,
+    ) -> Option<std::cmp::Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::ops::Add<Duration>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: std::ops::Add<Duration, Output = T>,
+    Tz: TimeZone,
+{
+    type Output = NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>;
+
+    #[inline]
+    fn add(self, rhs: Duration) -> Self::Output {
+        NaiveWithDefault::from(self.0.add(rhs))
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::ops::Add<FixedOffset>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: std::ops::Add<FixedOffset, Output = T>,
+    Tz: TimeZone,
+{
+    type Output = NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>;
+
+    #[inline]
+    fn add(self, rhs: FixedOffset) -> Self::Output {
+        NaiveWithDefault::from(self.0.add(rhs))
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::ops::AddAssign<Duration>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: std::ops::AddAssign<Duration>,
+    Tz: TimeZone,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Duration) {
+        self.0.add_assign(rhs);
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    std::ops::Sub<NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: std::ops::Sub<T, Output = Duration>,
+    Tz: TimeZone,
+{
+    type Output = Duration;
+
+    #[inline]
+    fn sub(self, rhs: NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>) -> Duration {
+        self.0.sub(rhs.0)
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::ops::Sub<Duration>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: std::ops::Sub<Duration, Output = T>,
+    Tz: TimeZone,
+{
+    type Output = NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>;
+
+    #[inline]
+    fn sub(self, rhs: Duration) -> Self::Output {
+        NaiveWithDefault::from(self.0.sub(rhs))
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::ops::Sub<FixedOffset>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: std::ops::Sub<FixedOffset, Output = T>,
+    Tz: TimeZone,
+{
+    type Output = NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>;
+
+    #[inline]
+    fn sub(self, rhs: FixedOffset) -> Self::Output {
+        NaiveWithDefault::from(self.0.sub(rhs))
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::ops::SubAssign<Duration>
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: std::ops::SubAssign<Duration>,
+    Tz: TimeZone,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Duration) {
+        self.0.sub_assign(rhs);
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> chrono::DurationRound
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: chrono::DurationRound,
+    Tz: TimeZone,
+{
+    type Err = T::Err;
+
+    fn duration_round(self, duration: Duration) -> Result<Self, Self::Err> {
+        self.0.duration_round(duration).map(NaiveWithDefault::from)
+    }
+
+    fn duration_trunc(self, duration: Duration) -> Result<Self, Self::Err> {
+        self.0.duration_trunc(duration).map(NaiveWithDefault::from)
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> chrono::Datelike
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: chrono::Datelike,
+    Tz: TimeZone,
+{
+    #[inline]
+    fn year(&self) -> i32 {
+        self.0.year()
+    }
+    #[inline]
+    fn month(&self) -> u32 {
+        self.0.month()
+    }
+    #[inline]
+    fn month0(&self) -> u32 {
+        self.0.month0()
+    }
+    #[inline]
+    fn day(&self) -> u32 {
+        self.0.day()
+    }
+    #[inline]
+    fn day0(&self) -> u32 {
+        self.0.day0()
+    }
+    #[inline]
+    fn ordinal(&self) -> u32 {
+        self.0.ordinal()
+    }
+    #[inline]
+    fn ordinal0(&self) -> u32 {
+        self.0.ordinal0()
+    }
+    #[inline]
+    fn weekday(&self) -> chrono::Weekday {
+        self.0.weekday()
+    }
+    #[inline]
+    fn iso_week(&self) -> chrono::IsoWeek {
+        self.0.iso_week()
+    }
+
+    #[inline]
+    fn with_year(&self, year: i32) -> Option<Self> {
+        self.0.with_year(year).map(NaiveWithDefault::from)
+    }
+
+    #[inline]
+    fn with_month(&self, month: u32) -> Option<Self> {
+        self.0.with_month(month).map(NaiveWithDefault::from)
+    }
+
+    #[inline]
+    fn with_month0(&self, month0: u32) -> Option<Self> {
+        self.0.with_month0(month0).map(NaiveWithDefault::from)
+    }
+
+    #[inline]
+    fn with_day(&self, day: u32) -> Option<Self> {
+        self.0.with_day(day).map(NaiveWithDefault::from)
+    }
+
+    #[inline]
+    fn with_day0(&self, day0: u32) -> Option<Self> {
+        self.0.with_day0(day0).map(NaiveWithDefault::from)
+    }
+
+    #[inline]
+    fn with_ordinal(&self, ordinal: u32) -> Option<Self> {
+        self.0.with_ordinal(ordinal).map(NaiveWithDefault::from)
+    }
+
+    #[inline]
+    fn with_ordinal0(&self, ordinal0: u32) -> Option<Self> {
+        self.0.with_ordinal0(ordinal0).map(NaiveWithDefault::from)
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> chrono::Timelike
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: chrono::Timelike,
+    Tz: TimeZone,
+{
+    #[inline]
+    fn hour(&self) -> u32 {
+        self.0.hour()
+    }
+    #[inline]
+    fn minute(&self) -> u32 {
+        self.0.minute()
+    }
+    #[inline]
+    fn second(&self) -> u32 {
+        self.0.second()
+    }
+    #[inline]
+    fn nanosecond(&self) -> u32 {
+        self.0.nanosecond()
+    }
+
+    #[inline]
+    fn with_hour(&self, hour: u32) -> Option<Self> {
+        self.0.with_hour(hour).map(NaiveWithDefault::from)
+    }
+
+    #[inline]
+    fn with_minute(&self, min: u32) -> Option<Self> {
+        self.0.with_minute(min).map(NaiveWithDefault::from)
+    }
+
+    #[inline]
+    fn with_second(&self, sec: u32) -> Option<Self> {
+        self.0.with_second(sec).map(NaiveWithDefault::from)
+    }
+
+    #[inline]
+    fn with_nanosecond(&self, nano: u32) -> Option<Self> {
+        self.0.with_nanosecond(nano).map(NaiveWithDefault::from)
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> std::fmt::Display
+    for NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: std::fmt::Display,
+    Tz: TimeZone,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::{Datelike, Duration, FixedOffset, NaiveDate, NaiveTime, Timelike, Utc};
+
+    use crate::{
+        FromUtc, NaiveDateDefaultToday, NaiveDateDefaultUnix, NaiveDateTimeDefaultNow,
+        NaiveTimeDefault,
+    };
+
+    #[test]
+    fn from_utc() {
+        let datetime = chrono::DateTime::<Utc>::default();
+
+        assert_eq!(
+            NaiveDateTimeDefaultNow::<FixedOffset, -5>::from_utc(&datetime).to_string(),
+            "1969-12-31 19:00:00"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let date: NaiveDateDefaultToday<Utc> = "2000-02-29".parse().unwrap();
+
+        assert_eq!(date.weekday(), chrono::Weekday::Tue);
+        assert!("2001-02-29".parse::<NaiveDateDefaultToday<Utc>>().is_err());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut date = NaiveDateDefaultToday::<Utc>::default();
+        date += Duration::days(1);
+
+        assert_eq!(
+            date - NaiveDateDefaultToday::<Utc>::default(),
+            Duration::days(1)
+        );
+        assert_eq!(
+            date.with_day(1).unwrap(),
+            NaiveDate::from_ymd_opt(2022, 10, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn timelike() {
+        let time = NaiveTimeDefault::default() + Duration::minutes(90);
+
+        assert_eq!(time.hour(), 1);
+        assert_eq!(
+            time.with_minute(0).unwrap(),
+            NaiveTime::from_hms_opt(1, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn comparing() {
+        let date = NaiveDate::from_ymd_opt(2022, 10, 10).unwrap();
+
+        assert!(date == NaiveDateDefaultToday::<Utc>::default());
+        assert!(date < NaiveDateDefaultToday::<FixedOffset, 9>::default());
+        assert!(NaiveDateDefaultToday::<FixedOffset, -9>::default() <= date);
+    }
+
+    #[test]
+    fn into_inner() {
+        let date = NaiveDateDefaultToday::<Utc>::default();
+        let inner: NaiveDate = date.into();
+
+        assert_eq!(date.into_inner(), inner);
+        assert_eq!(inner, NaiveDate::from_ymd_opt(2022, 10, 10).unwrap());
+    }
+
+    #[test]
+    fn mutate_in_place() {
+        let mut time = NaiveTimeDefault::default();
+
+        *time += Duration::hours(1);
+        *time.as_mut() += Duration::minutes(1);
+
+        assert_eq!(*time.as_ref(), NaiveTime::from_hms_opt(1, 1, 0).unwrap());
+    }
+
+    #[test]
+    fn borrow_as_key() {
+        let mut days = std::collections::HashSet::new();
+        days.insert(NaiveDateDefaultToday::<Utc>::default());
+
+        assert!(days.contains(&NaiveDate::from_ymd_opt(2022, 10, 10).unwrap()));
+    }
+
+    #[test]
+    fn now_and_unix() {
+        let today = NaiveDateDefaultToday::<Utc>::default();
+        let unix = NaiveDateDefaultUnix::from(today);
+
+        assert_eq!(unix, *today);
+        assert_eq!(NaiveDateDefaultToday::from(unix), today);
+    }
+}
@@ -0,0 +1,408 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{
+    DateTime, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Utc,
+};
+
+use crate::{
+    clock, default_provider::start_of, DateTimeWithDefault, FromUtc, NaivePart, NaiveWithDefault,
+};
+
+/// Epoch integers with at least this magnitude are milliseconds, and seconds otherwise.
+///
+/// 10^11 seconds is in the year 5138, and 10^11 milliseconds is in 1973.
+const EPOCH_MILLIS_THRESHOLD: i64 = 100_000_000_000;
+
+/// Error of [`DateTimeWithDefault::parse_lenient`].
+///
+/// The variant is the format closest to the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseLenientError {
+    /// A relative time such as `now-1h` with an invalid amount, unit or result.
+    Relative(String),
+    /// A word other than `now`, `today`, `yesterday` and `tomorrow`.
+    Keyword(String),
+    /// An epoch integer out of the range of `DateTime`.
+    Epoch(i64),
+    /// A datetime with an offset, such as RFC 3339.
+    Rfc3339(chrono::ParseError),
+    /// A datetime starting with a day of the week, or with a comma, such as RFC 2822.
+    Rfc2822(chrono::ParseError),
+    /// A date or datetime without an offset.
+    Local(chrono::ParseError),
+    /// A local datetime skipped in the time zone, e.g. by a DST change.
+    LocalDateTime(NaiveDateTime),
+}
+
+impl fmt::Display for ParseLenientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Relative(s) => write!(f, "invalid relative time such as now-1h: {s}"),
+            Self::Keyword(s) => write!(f, "unknown keyword: {s}"),
+            Self::Epoch(n) => write!(f, "epoch timestamp out of range: {n}"),
+            Self::Rfc3339(err) => write!(f, "invalid RFC 3339 datetime: {err}"),
+            Self::Rfc2822(err) => write!(f, "invalid RFC 2822 datetime: {err}"),
+            Self::Local(err) => write!(f, "invalid local datetime: {err}"),
+            Self::LocalDateTime(local) => write!(f, "no local datetime: {local}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseLenientError {}
+
+impl<Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Self: FromUtc,
+{
+    /// Parses the formats people write in command lines and config files,
+    /// and converts the result into the time zone of the NewType.
+    ///
+    /// - `now`, or `now` with `+`/`-` amounts of `w`, `d`, `h`, `m` and `s`, e.g. `now-1h30m`
+    /// - `today`, `yesterday` and `tomorrow`, the start of the day in the time zone of the NewType
+    /// - epoch seconds, or milliseconds from 10^11, e.g. `1700000000`
+    /// - RFC 3339 and ISO 8601 with an offset, e.g. `2024-05-01 12:00:00 +09:00`
+    /// - RFC 2822, e.g. `Wed, 01 May 2024 12:00:00 +0000`
+    /// - the `Display` of a `NamedTz` NewType (`chrono-tz` feature), e.g. `2024-05-01 12:00:00 +09:00 Asia/Tokyo`
+    /// - ISO 8601 without an offset, in the time zone of the NewType, e.g. `2024-05-01 12:00`
+    ///
+    /// `now` is taken from [`clock::now`].
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset};
+    /// use datetime_default::{clock, DateTimeDefaultNow, ParseLenientError};
+    ///
+    /// type Tokyo = DateTimeDefaultNow<FixedOffset, 9>;
+    ///
+    /// let now = DateTime::parse_from_rfc3339("2024-05-01T12:00:00Z").unwrap();
+    ///
+    /// clock::with_frozen_now(now, || {
+    ///     let parse = |s| Tokyo::parse_lenient(s).unwrap().to_rfc3339();
+    ///
+    ///     assert_eq!(parse("now-1h"), "2024-05-01T20:00:00+09:00");
+    ///     assert_eq!(parse("today"), "2024-05-01T00:00:00+09:00");
+    ///     assert_eq!(parse("1700000000"), "2023-11-15T07:13:20+09:00");
+    ///     assert_eq!(parse("2024-05-01 12:00"), "2024-05-01T12:00:00+09:00");
+    /// });
+    ///
+    /// assert!(matches!(
+    ///     Tokyo::parse_lenient("2024-05-01T12:00:00+25:00"),
+    ///     Err(ParseLenientError::Rfc3339(_))
+    /// ));
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<Self, ParseLenientError> {
+        let now = Self::from_utc(&clock::now());
+        let tz = now.timezone();
+
+        parse_lenient(s.trim(), &tz).map(|datetime| Self::from_utc(&datetime.with_timezone(&Utc)))
+    }
+}
+
+impl<T, Tz, P, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    NaiveWithDefault<T, Tz, P, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    T: NaivePart + FromStr,
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    DateTimeWithDefault<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>: FromUtc,
+{
+    /// Parses the naive value itself, or else any format of
+    /// [`DateTimeWithDefault::parse_lenient`] and keeps its local part.
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset};
+    /// use datetime_default::{clock, NaiveDateDefaultToday};
+    ///
+    /// type Tokyo = NaiveDateDefaultToday<FixedOffset, 9>;
+    ///
+    /// let now = DateTime::parse_from_rfc3339("2024-05-01T20:00:00Z").unwrap();
+    ///
+    /// clock::with_frozen_now(now, || {
+    ///     let parse = |s| Tokyo::parse_lenient(s).unwrap().to_string();
+    ///
+    ///     assert_eq!(parse("2024-04-01"), "2024-04-01");
+    ///     assert_eq!(parse("yesterday"), "2024-05-01");
+    ///     assert_eq!(parse("2024-04-01T20:00:00Z"), "2024-04-02");
+    /// });
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<Self, ParseLenientError> {
+        if let Ok(naive) = T::from_str(s.trim()) {
+            return Ok(Self::from(naive));
+        }
+
+        DateTimeWithDefault::<Tz, P, OFFSET_HOURS, OFFSET_MINUTES>::parse_lenient(s)
+            .map(|datetime| Self::from(T::from_local(&*datetime)))
+    }
+}
+
+fn parse_lenient<Tz: TimeZone>(s: &str, tz: &Tz) -> Result<DateTime<Tz>, ParseLenientError> {
+    let lower = s.to_ascii_lowercase();
+    if let Some(amounts) = lower.strip_prefix("now") {
+        return parse_relative(amounts)
+            .and_then(|duration| clock::now().checked_add_signed(duration))
+            .map(|datetime| datetime.with_timezone(tz))
+            .ok_or_else(|| ParseLenientError::Relative(s.to_owned()));
+    }
+    if !s.is_empty() && s.chars().all(|c| c.is_ascii_alphabetic()) {
+        let today = clock::now().with_timezone(tz).naive_local().date();
+        return match lower.as_str() {
+            "today" => Ok(start_of(tz, today)),
+            "yesterday" => Ok(start_of(tz, today.pred_opt().unwrap())),
+            "tomorrow" => Ok(start_of(tz, today.succ_opt().unwrap())),
+            _ => Err(ParseLenientError::Keyword(s.to_owned())),
+        };
+    }
+    if let Ok(n) = s.parse::<i64>() {
+        let datetime = if n.unsigned_abs() < EPOCH_MILLIS_THRESHOLD as u64 {
+            Utc.timestamp_opt(n, 0)
+        } else {
+            Utc.timestamp_millis_opt(n)
+        };
+        return datetime
+            .single()
+            .map(|datetime| datetime.with_timezone(tz))
+            .ok_or(ParseLenientError::Epoch(n));
+    }
+
+    let offset_err = match DateTime::<FixedOffset>::from_str(s) {
+        Ok(datetime) => return Ok(datetime.with_timezone(tz)),
+        Err(err) => err,
+    };
+    #[cfg(feature = "chrono-tz")]
+    if let Some(datetime) = crate::features::chrono_tz::parse_display(s) {
+        return Ok(datetime.with_timezone(tz));
+    }
+    let rfc2822_err = match DateTime::parse_from_rfc2822(s) {
+        Ok(datetime) => return Ok(datetime.with_timezone(tz)),
+        Err(err) => err,
+    };
+    let local_err = match parse_local(s) {
+        Ok(LocalInput::DateTime(local)) => {
+            return match tz.from_local_datetime(&local) {
+                LocalResult::Single(datetime) | LocalResult::Ambiguous(datetime, _) => Ok(datetime),
+                LocalResult::None => Err(ParseLenientError::LocalDateTime(local)),
+            }
+        }
+        Ok(LocalInput::Date(date)) => return Ok(start_of(tz, date)),
+        Err(err) => err,
+    };
+
+    if s.starts_with(|c: char| c.is_ascii_alphabetic()) || s.contains(',') {
+        Err(ParseLenientError::Rfc2822(rfc2822_err))
+    } else if s
+        .get(10..)
+        .is_some_and(|time| time.contains(['Z', 'z', '+', '-']))
+    {
+        Err(ParseLenientError::Rfc3339(offset_err))
+    } else {
+        Err(ParseLenientError::Local(local_err))
+    }
+}
+
+/// Parses the amounts after `now`, e.g. `+1h30m`.
+fn parse_relative(amounts: &str) -> Option<Duration> {
+    let amounts: String = amounts.chars().filter(|c| !c.is_whitespace()).collect();
+    let (negative, mut rest) = match amounts.as_bytes().first() {
+        None => return Some(Duration::zero()),
+        Some(b'+') => (false, &amounts[1..]),
+        Some(b'-') => (true, &amounts[1..]),
+        Some(_) => return None,
+    };
+    if rest.is_empty() {
+        return None;
+    }
+
+    let mut duration = Duration::zero();
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let amount: i64 = rest[..digits].parse().ok()?;
+        let unit = rest[digits..].chars().next()?;
+        let secs = match unit {
+            'w' => 7 * 24 * 60 * 60,
+            'd' => 24 * 60 * 60,
+            'h' => 60 * 60,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        // Keep within the range of `Duration::seconds`.
+        let secs = amount
+            .checked_mul(secs)
+            .filter(|secs| secs.abs() <= i64::MAX / 1000)?;
+        duration = duration.checked_add(&Duration::seconds(secs))?;
+        rest = &rest[digits + unit.len_utf8()..];
+    }
+
+    Some(if negative { -duration } else { duration })
+}
+
+enum LocalInput {
+    DateTime(NaiveDateTime),
+    Date(NaiveDate),
+}
+
+/// Parses ISO 8601 without an offset, with `T` or a space, and with or without seconds.
+fn parse_local(s: &str) -> Result<LocalInput, chrono::ParseError> {
+    let s = s.replacen(['T', 't'], " ", 1);
+    let err = match NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S%.f") {
+        Ok(local) => return Ok(LocalInput::DateTime(local)),
+        Err(err) => err,
+    };
+
+    NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M")
+        .map(LocalInput::DateTime)
+        .or_else(|_| NaiveDate::parse_from_str(&s, "%Y-%m-%d").map(LocalInput::Date))
+        .map_err(|_| err)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, FixedOffset, Utc};
+
+    use super::ParseLenientError;
+    use crate::{
+        clock, DateTimeDefaultNow, DateTimeDefaultUnix, NaiveDateTimeDefaultNow, NaiveTimeDefault,
+    };
+
+    type Tokyo = DateTimeDefaultNow<FixedOffset, 9>;
+
+    fn parse(s: &str) -> String {
+        Tokyo::parse_lenient(s).unwrap().to_rfc3339()
+    }
+
+    #[test]
+    fn relative() {
+        assert_eq!(parse("now"), "2022-10-11T08:40:11.695164300+09:00");
+        assert_eq!(parse("NOW + 30m"), "2022-10-11T09:10:11.695164300+09:00");
+        assert_eq!(parse("now-1d12h"), "2022-10-09T20:40:11.695164300+09:00");
+        assert_eq!(parse("now+2w"), "2022-10-25T08:40:11.695164300+09:00");
+    }
+
+    #[test]
+    fn keywords() {
+        assert_eq!(parse("today"), "2022-10-11T00:00:00+09:00");
+        assert_eq!(parse("Yesterday"), "2022-10-10T00:00:00+09:00");
+        assert_eq!(
+            DateTimeDefaultUnix::<Utc>::parse_lenient("tomorrow")
+                .unwrap()
+                .to_rfc3339(),
+            "2022-10-11T00:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn epoch() {
+        assert_eq!(parse("1700000000"), "2023-11-15T07:13:20+09:00");
+        assert_eq!(parse("1700000000123"), "2023-11-15T07:13:20.123+09:00");
+        assert_eq!(parse("-86400"), "1969-12-31T09:00:00+09:00");
+    }
+
+    #[test]
+    fn epoch_out_of_range() {
+        assert_eq!(
+            Tokyo::parse_lenient(&i64::MAX.to_string()),
+            Err(ParseLenientError::Epoch(i64::MAX))
+        );
+        assert_eq!(
+            Tokyo::parse_lenient(&i64::MIN.to_string()),
+            Err(ParseLenientError::Epoch(i64::MIN))
+        );
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(parse("2024-05-01T12:00:00Z"), "2024-05-01T21:00:00+09:00");
+        assert_eq!(
+            parse("2024-05-01 12:00:00.5 -05:00"),
+            "2024-05-02T02:00:00.500+09:00"
+        );
+        assert_eq!(
+            parse("Wed, 01 May 2024 12:00:00 +0000"),
+            "2024-05-01T21:00:00+09:00"
+        );
+        assert_eq!(parse("2024-05-01 12:00"), "2024-05-01T12:00:00+09:00");
+        assert_eq!(parse("2024-05-01T12:00:30"), "2024-05-01T12:00:30+09:00");
+        assert_eq!(parse("2024-05-01"), "2024-05-01T00:00:00+09:00");
+    }
+
+    #[test]
+    fn follows_clock() {
+        let now = DateTime::parse_from_rfc3339("2000-01-01T00:00:00Z").unwrap();
+
+        clock::with_frozen_now(now, || {
+            assert_eq!(parse("now+1h"), "2000-01-01T10:00:00+09:00");
+        });
+    }
+
+    #[test]
+    fn closest_format() {
+        assert_eq!(
+            Tokyo::parse_lenient("now+1y"),
+            Err(ParseLenientError::Relative("now+1y".to_owned()))
+        );
+        assert_eq!(
+            Tokyo::parse_lenient("now-"),
+            Err(ParseLenientError::Relative("now-".to_owned()))
+        );
+        assert_eq!(
+            Tokyo::parse_lenient("yesterdy"),
+            Err(ParseLenientError::Keyword("yesterdy".to_owned()))
+        );
+        assert_eq!(
+            Tokyo::parse_lenient("9223372036854775807"),
+            Err(ParseLenientError::Epoch(i64::MAX))
+        );
+        assert!(matches!(
+            Tokyo::parse_lenient("2024-05-01T25:00:00Z"),
+            Err(ParseLenientError::Rfc3339(_))
+        ));
+        assert!(matches!(
+            Tokyo::parse_lenient("Wed, 01 Foo 2024 12:00:00 +0000"),
+            Err(ParseLenientError::Rfc2822(_))
+        ));
+        assert!(matches!(
+            Tokyo::parse_lenient("2024-05-01 25:00"),
+            Err(ParseLenientError::Local(_))
+        ));
+        assert!(Tokyo::parse_lenient("")
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid local datetime"));
+    }
+
+    #[test]
+    fn naive() {
+        type Local = NaiveDateTimeDefaultNow<FixedOffset, 9>;
+
+        assert_eq!(
+            Local::parse_lenient("2024-05-01T12:00:00")
+                .unwrap()
+                .to_string(),
+            "2024-05-01 12:00:00"
+        );
+        assert_eq!(
+            Local::parse_lenient("now-1h").unwrap().to_string(),
+            "2022-10-11 07:40:11.695164300"
+        );
+        assert_eq!(
+            Local::parse_lenient("2024-05-01 12:00:00 +00:00")
+                .unwrap()
+                .to_string(),
+            "2024-05-01 21:00:00"
+        );
+        assert_eq!(
+            NaiveTimeDefault::parse_lenient("12:30:00")
+                .unwrap()
+                .to_string(),
+            "12:30:00"
+        );
+        assert!(matches!(
+            Local::parse_lenient("2024-05-01 25:00"),
+            Err(ParseLenientError::Local(_))
+        ));
+    }
+}